@@ -0,0 +1,104 @@
+//! `#[derive(FromPgRow)]`, re-exported by `serde-sqlx` under its `derive` feature
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, LitStr};
+
+/// Implements `serde_sqlx::FromPgRow` for a struct with named fields, reading each
+/// field from the column of the same name.
+///
+/// `#[from_pg_row(rename = "...")]` or its alias `#[from_pg_row(column = "...")]`
+/// reads a field from a differently named column instead
+#[proc_macro_derive(FromPgRow, attributes(from_pg_row))]
+pub fn derive_from_pg_row(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    "FromPgRow can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "FromPgRow can only be derived for structs",
+            ))
+        }
+    };
+
+    let reads = fields
+        .iter()
+        .map(|field| {
+            let ident = field.ident.as_ref().expect("named fields have idents");
+            let column = match column_name(field)? {
+                Some(column) => column,
+                None => ident.to_string(),
+            };
+
+            Ok(quote! {
+                #ident: ::serde_sqlx::__private::column(row, #column)?
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    // Every field is deserialized on its own, which generic fields need spelled out
+    let mut generics = input.generics.clone();
+    let where_clause = generics.make_where_clause();
+    for field in fields {
+        let ty = &field.ty;
+        where_clause
+            .predicates
+            .push(syn::parse_quote!(#ty: for<'de> ::serde_sqlx::__private::Deserialize<'de>));
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::serde_sqlx::FromPgRow for #name #ty_generics #where_clause {
+            fn from_pg_row(
+                row: &::serde_sqlx::__private::PgRow,
+            ) -> ::std::result::Result<Self, ::serde_sqlx::DeserializeError> {
+                ::std::result::Result::Ok(Self { #(#reads),* })
+            }
+        }
+    })
+}
+
+/// The column given by the field's `rename` or `column` attribute, if any
+fn column_name(field: &syn::Field) -> syn::Result<Option<String>> {
+    let mut column: Option<LitStr> = None;
+
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("from_pg_row"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename") || meta.path.is_ident("column") {
+                let value: LitStr = meta.value()?.parse()?;
+                if column.is_some() {
+                    return Err(meta.error("the column name is already set"));
+                }
+                column = Some(value);
+                Ok(())
+            } else {
+                Err(meta.error("expected `rename` or `column`"))
+            }
+        })?;
+    }
+
+    Ok(column.map(|column| column.value()))
+}
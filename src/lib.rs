@@ -1,42 +1,373 @@
-use deserializers::PgRowDeserializer;
+pub use config::{DeserializerConfig, TimestampFormat};
+pub use debug::{debug_row, PgRowDebug};
+pub use deserializers::OwnedPgValue;
+pub use deserializers::{PgRowDeserializer, PgRowDeserializerBuilder, PgValueDeserializer};
+pub use dispatch::{ErasedVisitor, PgTypeDispatch, PgTypeHandler};
+pub use error::DeserializeError;
+pub use from_row::FromPgRow;
+pub use inspect::PgRowInspector;
+pub use iter::PgRowColumns;
+pub use json::PgJson;
+pub use range::PgRange;
+use serde::de::Deserialize;
 use serde::de::Error;
-use serde::de::{value::Error as DeError, Deserialize};
+#[cfg(feature = "derive")]
+pub use serde_sqlx_derive::FromPgRow;
+pub use serialize::PgRowSerializer;
 
-use sqlx::postgres::{PgRow, PgValueRef};
+use sqlx::postgres::{PgRow, PgTypeInfo, PgTypeKind, PgValueRef};
+use sqlx::{Column, Row, TypeInfo};
+
+#[cfg(all(feature = "rust_decimal", feature = "bigdecimal"))]
+compile_error!("the `rust_decimal` and `bigdecimal` features are mutually exclusive");
+
+/// `tracing::$level!` with the `tracing` feature, otherwise `log::$level!` with the
+/// `logging` feature. Compiled out when neither is enabled
+macro_rules! pg_log {
+    ($level:ident, $($arg:tt)+) => {{
+        #[cfg(feature = "tracing")]
+        tracing::$level!($($arg)+);
+        #[cfg(all(feature = "logging", not(feature = "tracing")))]
+        log::$level!($($arg)+);
+        #[cfg(not(any(feature = "logging", feature = "tracing")))]
+        let _ = format_args!($($arg)+);
+    }};
+}
 
 /// Convenience function: deserialize a PgRow into any T that implements Deserialize
-pub fn from_pg_row<T>(row: PgRow) -> Result<T, DeError>
+///
+/// A row without columns, e.g. from `SELECT`, deserializes into `()` (or
+/// `Some(())`, or a unit struct)
+pub fn from_pg_row<T>(row: PgRow) -> Result<T, DeserializeError>
+where
+    T: for<'de> Deserialize<'de>,
+{
+    from_pg_row_ref(&row)
+}
+
+/// Like [`from_pg_row`], but borrows the row so it can still be used afterwards
+pub fn from_pg_row_ref<T>(row: &PgRow) -> Result<T, DeserializeError>
 where
     T: for<'de> Deserialize<'de>,
 {
-    let deserializer = PgRowDeserializer::new(&row);
+    deserialize_row(PgRowDeserializer::builder().build(row))
+}
+
+/// The path every `from_pg_row*` function takes, so each is instrumented the same way
+fn deserialize_row<T>(deserializer: PgRowDeserializer<'_>) -> Result<T, DeserializeError>
+where
+    T: for<'de> Deserialize<'de>,
+{
+    #[cfg(feature = "tracing")]
+    let _span =
+        tracing::debug_span!("from_pg_row", columns = deserializer.column_count()).entered();
+
     T::deserialize(deserializer)
 }
 
-fn decode_raw_pg<'a, T>(raw_value: PgValueRef<'a>) -> Result<T, DeError>
+/// Deserializes a single column, by position, without touching the rest of the row
+pub fn from_pg_row_column<T>(row: &PgRow, index: usize) -> Result<T, DeserializeError>
+where
+    T: for<'de> Deserialize<'de>,
+{
+    let value = row.try_get_raw(index).map_err(DeserializeError::custom)?;
+
+    T::deserialize(PgValueDeserializer {
+        value,
+        column: Some(row.columns()[index].name()),
+        config: DeserializerConfig::default_ref(),
+    })
+}
+
+/// Deserializes a single column, by name, without touching the rest of the row
+pub fn from_pg_row_column_by_name<T>(row: &PgRow, name: &str) -> Result<T, DeserializeError>
+where
+    T: for<'de> Deserialize<'de>,
+{
+    let Some(column) = row.columns().iter().find(|column| column.name() == name) else {
+        return Err(DeserializeError::custom(format_args!(
+            "missing column '{name}'"
+        )));
+    };
+
+    from_pg_row_column(row, column.ordinal())
+}
+
+/// Deserializes the row over an existing value, e.g. to refresh it in a polling loop.
+///
+/// The whole value is replaced, so a field whose column is left out of the query
+/// gets its `#[serde(default)]` rather than keeping its old value. On error,
+/// `target` is left as it was
+pub fn update_from_pg_row<T>(row: PgRow, target: &mut T) -> Result<(), DeserializeError>
+where
+    T: for<'de> Deserialize<'de>,
+{
+    *target = from_pg_row_ref(&row)?;
+    Ok(())
+}
+
+/// Deserializes every row, e.g. the result of `fetch_all`, stopping at the first error
+///
+/// Each row is one element: a two-column query into `Vec<(A, B)>` gives one pair
+/// per row, whereas [`from_pg_row`] into a `Vec` reads a single row, e.g. its array column
+pub fn from_pg_rows<T>(rows: Vec<PgRow>) -> Result<Vec<T>, DeserializeError>
+where
+    T: for<'de> Deserialize<'de>,
+{
+    from_pg_rows_ref(&rows)
+}
+
+/// Like [`from_pg_rows`], but borrows the rows
+pub fn from_pg_rows_ref<T>(rows: &[PgRow]) -> Result<Vec<T>, DeserializeError>
+where
+    T: for<'de> Deserialize<'de>,
+{
+    rows.iter().map(from_pg_row_ref).collect()
+}
+
+/// Like [`from_pg_row`], but deserializes according to the given [`DeserializerConfig`]
+pub fn from_pg_row_with_config<T>(
+    row: PgRow,
+    config: &DeserializerConfig,
+) -> Result<T, DeserializeError>
+where
+    T: for<'de> Deserialize<'de>,
+{
+    deserialize_row(PgRowDeserializer::builder().with_config(config).build(&row))
+}
+
+/// Like [`from_pg_row`], but passes each column name through `rename` before
+/// matching it to a field, e.g. to strip a common `tbl_` prefix
+pub fn from_pg_row_with_rename<T, F>(row: PgRow, rename: F) -> Result<T, DeserializeError>
+where
+    F: Fn(&str) -> String,
+    T: for<'de> Deserialize<'de>,
+{
+    deserialize_row(
+        PgRowDeserializer::builder()
+            .with_rename(&rename)
+            .build(&row),
+    )
+}
+
+/// OIDs of the array types in the default `pg_type` catalog
+const PG_ARRAY_OIDS: &[u32] = &[
+    143,  // XML[]
+    199,  // JSON[]
+    629,  // LINE[]
+    651,  // CIDR[]
+    719,  // CIRCLE[]
+    775,  // MACADDR8[]
+    791,  // MONEY[]
+    1000, // BOOL[]
+    1001, // BYTEA[]
+    1002, // "CHAR"[]
+    1003, // NAME[]
+    1005, // INT2[]
+    1006, // INT2VECTOR[]
+    1007, // INT4[]
+    1008, // REGPROC[]
+    1009, // TEXT[]
+    1010, // TID[]
+    1011, // XID[]
+    1012, // CID[]
+    1013, // OIDVECTOR[]
+    1014, // BPCHAR[]
+    1015, // VARCHAR[]
+    1016, // INT8[]
+    1017, // POINT[]
+    1018, // LSEG[]
+    1019, // PATH[]
+    1020, // BOX[]
+    1021, // FLOAT4[]
+    1022, // FLOAT8[]
+    1027, // POLYGON[]
+    1028, // OID[]
+    1034, // ACLITEM[]
+    1040, // MACADDR[]
+    1041, // INET[]
+    1115, // TIMESTAMP[]
+    1182, // DATE[]
+    1183, // TIME[]
+    1185, // TIMESTAMPTZ[]
+    1187, // INTERVAL[]
+    1231, // NUMERIC[]
+    1263, // CSTRING[]
+    1270, // TIMETZ[]
+    1561, // BIT[]
+    1563, // VARBIT[]
+    2201, // REFCURSOR[]
+    2207, // REGPROCEDURE[]
+    2208, // REGOPER[]
+    2209, // REGOPERATOR[]
+    2210, // REGCLASS[]
+    2211, // REGTYPE[]
+    2287, // RECORD[]
+    2951, // UUID[]
+    3221, // PG_LSN[]
+    3643, // TSVECTOR[]
+    3645, // TSQUERY[]
+    3807, // JSONB[]
+    3905, // INT4RANGE[]
+    3907, // NUMRANGE[]
+    3909, // TSRANGE[]
+    3911, // TSTZRANGE[]
+    3913, // DATERANGE[]
+    3927, // INT8RANGE[]
+    4073, // JSONPATH[]
+];
+
+/// Whether `type_info` is a Postgres array type.
+///
+/// The type name is checked first (`INT4[]`, `TEXT[]`, ...), falling back to the
+/// OIDs of the built-in array types for when the name doesn't give it away.
+pub fn is_pg_array_type(type_info: &PgTypeInfo) -> bool {
+    type_info.name().ends_with("[]")
+        || type_info
+            .oid()
+            .is_some_and(|oid| PG_ARRAY_OIDS.contains(&oid.0))
+}
+
+/// Whether the type is an anonymous `RECORD`, e.g. from `ROW(1, 'a')`, or a
+/// composite type
+fn is_pg_record_type(type_info: &PgTypeInfo) -> bool {
+    type_info.name() == "RECORD"
+        || matches!(pg_type_kind(type_info), Some(PgTypeKind::Composite(_)))
+}
+
+/// Whether the type was created with `CREATE TYPE ... AS ENUM`
+fn is_pg_enum_type(type_info: &PgTypeInfo) -> bool {
+    matches!(pg_type_kind(type_info), Some(PgTypeKind::Enum(_)))
+}
+
+/// The type's kind, unless sqlx couldn't look the type up, e.g. a custom type in a
+/// `raw_sql` result. `PgTypeInfo::kind` panics for those, which it names `?`
+fn pg_type_kind(type_info: &PgTypeInfo) -> Option<&PgTypeKind> {
+    let resolved = type_info.oid().is_some() && type_info.name() != "?";
+
+    resolved.then(|| type_info.kind())
+}
+
+/// Like [`from_pg_row`], but matches column names to struct fields ignoring ASCII case.
+///
+/// See [`DeserializerConfig::case_insensitive_columns`].
+pub fn from_pg_row_ci<T>(row: PgRow) -> Result<T, DeserializeError>
+where
+    T: for<'de> Deserialize<'de>,
+{
+    let config = DeserializerConfig {
+        case_insensitive_columns: true,
+        ..Default::default()
+    };
+
+    from_pg_row_with_config(row, &config)
+}
+
+/// Like [`from_pg_row`], but columns that don't match a field of the target struct
+/// are skipped without being decoded, e.g. for a `SELECT *` into a narrower struct
+pub fn from_pg_row_lenient<T>(row: PgRow) -> Result<T, DeserializeError>
+where
+    T: for<'de> Deserialize<'de>,
+{
+    let config = DeserializerConfig {
+        skip_unknown_columns: true,
+        ..Default::default()
+    };
+
+    from_pg_row_with_config(row, &config)
+}
+
+/// Whether values of this type are read as a sequence of coordinates. Only POINT
+/// without the `geometry` feature
+fn is_pg_geometry_type(type_name: &str) -> bool {
+    type_name == "POINT"
+        || cfg!(feature = "geometry")
+            && matches!(
+                type_name,
+                "LINE" | "LSEG" | "BOX" | "PATH" | "POLYGON" | "CIRCLE"
+            )
+}
+
+/// Converts a row to a JSON object keyed by column name, e.g. for an API response.
+///
+/// Unlike [`from_pg_row_ref`] into a `serde_json::Value`, this is always an object,
+/// even for a single column
+pub fn row_to_json(row: &PgRow) -> Result<serde_json::Value, DeserializeError> {
+    row.columns()
+        .iter()
+        .map(|column| Ok((column.name().to_owned(), column_to_json(row, column)?)))
+        .collect::<Result<serde_json::Map<_, _>, _>>()
+        .map(serde_json::Value::Object)
+}
+
+fn column_to_json(
+    row: &PgRow,
+    column: &sqlx::postgres::PgColumn,
+) -> Result<serde_json::Value, DeserializeError> {
+    from_pg_row_column(row, column.ordinal())
+}
+
+/// Converts a single value to its closest JSON representation, the same way
+/// [`row_to_json`] converts each column, e.g. for a dynamic schema
+pub fn pg_value_to_json(value: PgValueRef<'_>) -> Result<serde_json::Value, DeserializeError> {
+    serde_json::Value::deserialize(PgValueDeserializer::new(value))
+}
+
+/// Converts every row with [`row_to_json`], into a JSON array of objects
+pub fn rows_to_json(rows: Vec<PgRow>) -> Result<serde_json::Value, DeserializeError> {
+    rows.iter()
+        .map(row_to_json)
+        .collect::<Result<_, _>>()
+        .map(serde_json::Value::Array)
+}
+
+/// Whether the row has a column called `name`, e.g. to pick a target type for
+/// queries that may leave some columns out
+pub fn has_column(row: &PgRow, name: &str) -> bool {
+    row.columns().iter().any(|column| column.name() == name)
+}
+
+/// The Postgres type name of the column called `name`, e.g. `"INT4"` or `"TEXT[]"`
+pub fn column_type_name<'a>(row: &'a PgRow, name: &str) -> Option<&'a str> {
+    row.columns()
+        .iter()
+        .find(|column| column.name() == name)
+        .map(|column| column.type_info().name())
+}
+
+fn decode_raw_pg<'a, T>(raw_value: PgValueRef<'a>) -> Result<T, DeserializeError>
 where
     T: sqlx::Decode<'a, sqlx::Postgres>,
 {
     T::decode(raw_value).map_err(|err| {
-        DeError::custom(format!(
+        let message = format!(
             "Failed to decode {} value: {:?}",
             std::any::type_name::<T>(),
             err,
-        ))
+        );
+        // The error is returned, and may well be handled, e.g. by an untagged enum
+        pg_log!(debug, "{message}");
+
+        DeserializeError::custom(message)
     })
 }
 
 mod seq_access {
     use std::fmt::Debug;
+    use std::marker::PhantomData;
+    use std::ops::Range;
 
-    use serde::de::{value::Error as DeError, DeserializeSeed, SeqAccess, Visitor};
-    use serde::ser::Error as _;
+    use serde::de::Error as _;
+    use serde::de::{DeserializeSeed, SeqAccess, Visitor};
     use serde::{de, forward_to_deserialize_any};
-    use sqlx::{postgres::PgValueRef, Row};
+    use sqlx::postgres::{PgRow, PgValueFormat, PgValueRef};
+    use sqlx::{Column, Row};
 
+    use crate::DeserializeError;
     use crate::{
         decode_raw_pg,
         deserializers::{PgRowDeserializer, PgValueDeserializer},
+        map_access::PgColumnGroupDeserializer,
     };
 
     /// A SeqAccess implementation that iterates over the row’s columns
@@ -46,21 +377,21 @@ mod seq_access {
     }
 
     impl<'de, 'a> SeqAccess<'de> for PgRowSeqAccess<'a> {
-        type Error = DeError;
+        type Error = DeserializeError;
 
         fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
         where
             T: DeserializeSeed<'de>,
         {
             if self.deserializer.index < self.num_cols {
-                let value = self
-                    .deserializer
-                    .row
-                    .try_get_raw(self.deserializer.index)
-                    .map_err(DeError::custom)?;
+                pg_log!(
+                    trace,
+                    "Deserializing sequence element {}",
+                    self.deserializer.index
+                );
 
                 // Create a PgValueDeserializer for the current column.
-                let pg_value_deserializer = PgValueDeserializer { value };
+                let pg_value_deserializer = self.deserializer.value_deserializer()?;
 
                 self.deserializer.index += 1;
 
@@ -72,6 +403,135 @@ mod seq_access {
         }
     }
 
+    /// The columns making up one tuple element
+    pub(crate) struct PgColumnGroup {
+        pub(crate) columns: Range<usize>,
+        /// Length of the `_N_` prefix, or `None` for a lone `_N` column
+        pub(crate) prefix_len: Option<usize>,
+    }
+
+    /// Parses a `_N` or `_N_name` column name into `N` and the length of the
+    /// prefix to strip, if any
+    fn tuple_prefix(name: &str) -> Option<(usize, Option<usize>)> {
+        let rest = name.strip_prefix('_')?;
+        let digits = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let element = rest[..digits].parse().ok()?;
+
+        match &rest[digits..] {
+            "" => Some((element, None)),
+            suffix if suffix.len() > 1 && suffix.starts_with('_') => {
+                Some((element, Some(digits + 2)))
+            }
+            _ => None,
+        }
+    }
+
+    /// Groups the row's columns into tuple elements when they are named
+    /// `_0_one, _0_two, _1`, and so on. `None` unless every column follows that
+    /// pattern and at least one of them has a `_N_` prefix
+    pub(crate) fn tuple_column_groups(row: &PgRow) -> Option<Vec<PgColumnGroup>> {
+        let mut groups: Vec<(usize, PgColumnGroup)> = Vec::new();
+
+        for (idx, column) in row.columns().iter().enumerate() {
+            let (element, prefix_len) = tuple_prefix(column.name())?;
+
+            match groups.last_mut() {
+                Some((last, group)) if *last == element => {
+                    // A lone `_N` can't share its element with other columns
+                    if prefix_len.is_none() || group.prefix_len.is_none() {
+                        return None;
+                    }
+                    group.columns.end = idx + 1;
+                }
+                _ => groups.push((
+                    element,
+                    PgColumnGroup {
+                        columns: idx..idx + 1,
+                        prefix_len,
+                    },
+                )),
+            }
+        }
+
+        let groups: Vec<_> = groups.into_iter().map(|(_, group)| group).collect();
+
+        groups
+            .iter()
+            .any(|group| group.prefix_len.is_some())
+            .then_some(groups)
+    }
+
+    /// A SeqAccess over the tuple elements of a row whose columns are grouped
+    /// by their `_N_` prefix
+    pub(crate) struct PgColumnGroupSeqAccess<'a> {
+        pub(crate) deserializer: PgRowDeserializer<'a>,
+        pub(crate) groups: std::vec::IntoIter<PgColumnGroup>,
+    }
+
+    impl<'de> SeqAccess<'de> for PgColumnGroupSeqAccess<'_> {
+        type Error = DeserializeError;
+
+        fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
+        where
+            T: DeserializeSeed<'de>,
+        {
+            let Some(group) = self.groups.next() else {
+                return Ok(None);
+            };
+
+            let mut deserializer = self.deserializer;
+            deserializer.index = group.columns.start;
+
+            match group.prefix_len {
+                None => seed
+                    .deserialize(deserializer.value_deserializer()?)
+                    .map(Some),
+                Some(prefix_len) => seed
+                    .deserialize(PgColumnGroupDeserializer {
+                        deserializer,
+                        end: group.columns.end,
+                        prefix_len,
+                    })
+                    .map(Some),
+            }
+        }
+
+        fn size_hint(&self) -> Option<usize> {
+            Some(self.groups.len())
+        }
+    }
+
+    /// A SeqAccess that yields a single, non-array value once
+    pub(crate) struct PgValueSeqAccess<'a> {
+        value: Option<PgValueDeserializer<'a>>,
+    }
+
+    impl<'a> PgValueSeqAccess<'a> {
+        pub(crate) fn new(value: PgValueDeserializer<'a>) -> Self {
+            PgValueSeqAccess { value: Some(value) }
+        }
+    }
+
+    impl<'de> SeqAccess<'de> for PgValueSeqAccess<'_> {
+        type Error = DeserializeError;
+
+        fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
+        where
+            T: DeserializeSeed<'de>,
+        {
+            self.value
+                .take()
+                .map(|value| seed.deserialize(value))
+                .transpose()
+        }
+
+        fn size_hint(&self) -> Option<usize> {
+            Some(usize::from(self.value.is_some()))
+        }
+    }
+
     use serde::de::IntoDeserializer;
 
     /// SeqAccess implementation for Postgres arrays
@@ -81,14 +541,27 @@ mod seq_access {
         iter: std::vec::IntoIter<Option<T>>,
     }
 
-    impl<'de, 'a, T> PgArraySeqAccess<T>
+    impl<T> Debug for PgArraySeqAccess<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("PgArraySeqAccess")
+                .field("remaining", &self.iter.len())
+                .finish()
+        }
+    }
+
+    impl<'a, T> PgArraySeqAccess<T>
     where
         T: sqlx::Decode<'a, sqlx::Postgres> + Debug,
     {
-        pub fn new(value: PgValueRef<'a>) -> Result<Self, DeError>
+        pub fn new(value: PgValueRef<'a>) -> Result<Self, DeserializeError>
         where
             Vec<Option<T>>: sqlx::Decode<'a, sqlx::Postgres> + Debug,
         {
+            #[cfg(feature = "tracing")]
+            let _span =
+                tracing::trace_span!("decode_pg_array", element = std::any::type_name::<T>())
+                    .entered();
+
             let vec: Vec<Option<T>> = decode_raw_pg(value)?;
 
             Ok(PgArraySeqAccess {
@@ -99,9 +572,9 @@ mod seq_access {
 
     impl<'de, T> SeqAccess<'de> for PgArraySeqAccess<T>
     where
-        T: IntoDeserializer<'de, DeError>,
+        T: IntoDeserializer<'de, DeserializeError>,
     {
-        type Error = DeError;
+        type Error = DeserializeError;
 
         fn next_element_seed<U>(&mut self, seed: U) -> Result<Option<U::Value>, Self::Error>
         where
@@ -114,496 +587,4393 @@ mod seq_access {
             seed.deserialize(PgArrayElementDeserializer { value })
                 .map(Some)
         }
+
+        fn size_hint(&self) -> Option<usize> {
+            Some(self.iter.len())
+        }
     }
 
-    /// Yet another deserializer, this time to handles Options
-    struct PgArrayElementDeserializer<T> {
-        pub value: Option<T>,
+    /// SeqAccess implementation for Postgres arrays that does not materialize
+    /// the whole array upfront.
+    ///
+    /// Elements are decoded one at a time straight from the borrowed bytes of
+    /// the binary wire format, so a visitor that stops early never pays for the
+    /// rest of the array. Only available for element types implementing
+    /// [`PgArrayElement`]; everything else goes through [`PgArraySeqAccess`].
+    ///
+    /// Multi-dimensional arrays are yielded as nested sequences, e.g. an
+    /// `INT4[][]` into a `Vec<Vec<i32>>`.
+    pub struct PgArraySeqAccessRef<'a, T> {
+        elements: PgArrayElements<'a>,
+        /// Length of each dimension, outermost first
+        dims: Vec<usize>,
+        /// Items left in the outermost dimension
+        remaining: usize,
+        _element: PhantomData<T>,
     }
 
-    impl<'de, T> de::Deserializer<'de> for PgArrayElementDeserializer<T>
+    impl<T> Debug for PgArraySeqAccessRef<'_, T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("PgArraySeqAccessRef")
+                .field("dims", &self.dims)
+                .field("remaining", &self.remaining)
+                .finish()
+        }
+    }
+
+    impl<'a, T> PgArraySeqAccessRef<'a, T>
     where
-        T: IntoDeserializer<'de, DeError>,
+        T: PgArrayElement<'a>,
     {
-        type Error = DeError;
-
-        fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
-        where
-            V: Visitor<'de>,
-        {
-            match self.value {
-                Some(v) => visitor.visit_some(v.into_deserializer()),
-                None => visitor.visit_none(),
+        pub fn new(value: PgValueRef<'a>) -> Result<Self, DeserializeError> {
+            if value.format() != PgValueFormat::Binary {
+                return Err(DeserializeError::custom(
+                    "streaming array decode requires the binary wire format",
+                ));
             }
+
+            let bytes = value.as_bytes().map_err(|err| {
+                DeserializeError::custom(format!("Failed to read array bytes: {err}"))
+            })?;
+
+            let (elements, dims) = PgArrayElements::new(bytes)?;
+
+            Ok(PgArraySeqAccessRef {
+                elements,
+                remaining: dims.first().copied().unwrap_or(0),
+                dims,
+                _element: PhantomData,
+            })
         }
+    }
 
-        fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    impl<'de, 'a, T> SeqAccess<'de> for PgArraySeqAccessRef<'a, T>
+    where
+        T: PgArrayElement<'a> + IntoDeserializer<'de, DeserializeError>,
+    {
+        type Error = DeserializeError;
+
+        fn next_element_seed<U>(&mut self, seed: U) -> Result<Option<U::Value>, Self::Error>
         where
-            V: Visitor<'de>,
+            U: DeserializeSeed<'de>,
         {
-            match self.value {
-                Some(v) => v.into_deserializer().deserialize_any(visitor),
-                None => Err(DeError::custom(
-                    "unexpected null in non-optional array element",
-                )),
-            }
+            next_array_item::<T, U>(&mut self.elements, &self.dims, &mut self.remaining, seed)
         }
 
-        forward_to_deserialize_any! {
-            bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
-            bytes byte_buf unit unit_struct newtype_struct seq tuple tuple_struct
-            map struct enum identifier ignored_any
+        fn size_hint(&self) -> Option<usize> {
+            Some(self.remaining)
         }
     }
-}
 
-mod map_access {
-    use serde::de::{self, value::Error as DeError, IntoDeserializer, MapAccess};
-    use serde::ser::Error as _;
+    /// Yields the next item of the dimension `dims[0]`, of which `remaining` are
+    /// left: an element for the innermost dimension, a nested array otherwise
+    fn next_array_item<'de, 'a, T, U>(
+        elements: &mut PgArrayElements<'a>,
+        dims: &[usize],
+        remaining: &mut usize,
+        seed: U,
+    ) -> Result<Option<U::Value>, DeserializeError>
+    where
+        T: PgArrayElement<'a> + IntoDeserializer<'de, DeserializeError>,
+        U: DeserializeSeed<'de>,
+    {
+        if *remaining == 0 {
+            return Ok(None);
+        }
+        *remaining -= 1;
 
-    use sqlx::{Column, Row};
+        if dims.len() > 1 {
+            return seed
+                .deserialize(PgSubArrayDeserializer::<T> {
+                    elements,
+                    dims: &dims[1..],
+                    _element: PhantomData,
+                })
+                .map(Some);
+        }
 
-    use crate::deserializers::{PgRowDeserializer, PgValueDeserializer};
+        let element = elements
+            .next()
+            .ok_or_else(|| DeserializeError::custom("unexpected end of array data"))??;
+        let value = element.map(T::decode_element).transpose()?;
 
-    pub(crate) struct PgRowMapAccess<'a> {
-        pub(crate) deserializer: PgRowDeserializer<'a>,
-        pub(crate) num_cols: usize,
+        seed.deserialize(PgArrayElementDeserializer { value })
+            .map(Some)
     }
 
-    impl<'de, 'a> MapAccess<'de> for PgRowMapAccess<'a> {
-        type Error = DeError;
+    /// One item of a multi-dimensional array's outer dimension, itself an array
+    /// over the remaining `dims`
+    struct PgSubArrayDeserializer<'s, 'a, T> {
+        elements: &'s mut PgArrayElements<'a>,
+        dims: &'s [usize],
+        _element: PhantomData<T>,
+    }
 
-        fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+    impl<'de, 'a, T> de::Deserializer<'de> for PgSubArrayDeserializer<'_, 'a, T>
+    where
+        T: PgArrayElement<'a> + IntoDeserializer<'de, DeserializeError>,
+    {
+        type Error = DeserializeError;
+
+        fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
         where
-            K: de::DeserializeSeed<'de>,
+            V: Visitor<'de>,
         {
-            if self.deserializer.index < self.num_cols {
-                let col_name = self.deserializer.row.columns()[self.deserializer.index].name();
-                // Use the column name as the key
-                seed.deserialize(col_name.into_deserializer()).map(Some)
-            } else {
-                Ok(None)
+            let mut seq_access = PgSubArraySeqAccess::<T> {
+                elements: self.elements,
+                dims: self.dims,
+                remaining: self.dims[0],
+                _element: PhantomData,
+            };
+            let value = visitor.visit_seq(&mut seq_access)?;
+
+            // Leftovers would shift every item after this one
+            if seq_access.remaining > 0 {
+                return Err(DeserializeError::custom(format!(
+                    "{} trailing items in a sub-array of length {}",
+                    seq_access.remaining, self.dims[0]
+                )));
             }
+
+            Ok(value)
         }
 
-        fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+        fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
         where
-            V: de::DeserializeSeed<'de>,
+            V: Visitor<'de>,
         {
-            let value = self
-                .deserializer
-                .row
-                .try_get_raw(self.deserializer.index)
-                .map_err(DeError::custom)?;
-            let pg_type_deserializer = PgValueDeserializer { value };
-
-            self.deserializer.index += 1;
+            visitor.visit_some(self)
+        }
 
-            seed.deserialize(pg_type_deserializer)
+        forward_to_deserialize_any! {
+            bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+            bytes byte_buf unit unit_struct newtype_struct seq tuple
+            tuple_struct map struct enum identifier ignored_any
         }
     }
-}
-
-mod deserializers {
-    use crate::decode_raw_pg;
-    use crate::json::PgJson;
-    use crate::map_access::PgRowMapAccess;
-    use crate::seq_access::{PgArraySeqAccess, PgRowSeqAccess};
-    use serde::de::{value::Error as DeError, Deserializer, Visitor};
-    use serde::de::{Error as _, IntoDeserializer};
-    use serde::forward_to_deserialize_any;
-    use sqlx::postgres::{PgRow, PgValueRef};
-    use sqlx::{Row, TypeInfo, ValueRef};
 
-    #[derive(Clone, Copy)]
-    pub struct PgRowDeserializer<'a> {
-        pub(crate) row: &'a PgRow,
-        pub(crate) index: usize,
+    struct PgSubArraySeqAccess<'s, 'a, T> {
+        elements: &'s mut PgArrayElements<'a>,
+        dims: &'s [usize],
+        remaining: usize,
+        _element: PhantomData<T>,
     }
 
-    impl<'a> PgRowDeserializer<'a> {
-        pub fn new(row: &'a PgRow) -> Self {
-            PgRowDeserializer { row, index: 0 }
+    impl<'de, 'a, T> SeqAccess<'de> for PgSubArraySeqAccess<'_, 'a, T>
+    where
+        T: PgArrayElement<'a> + IntoDeserializer<'de, DeserializeError>,
+    {
+        type Error = DeserializeError;
+
+        fn next_element_seed<U>(&mut self, seed: U) -> Result<Option<U::Value>, Self::Error>
+        where
+            U: DeserializeSeed<'de>,
+        {
+            next_array_item::<T, U>(self.elements, self.dims, &mut self.remaining, seed)
         }
 
-        #[allow(unused)]
-        pub fn is_json(&self) -> bool {
-            self.row.try_get_raw(0).map_or(false, |value| {
-                matches!(value.type_info().name(), "JSON" | "JSONB")
-            })
+        fn size_hint(&self) -> Option<usize> {
+            Some(self.remaining)
         }
     }
 
-    impl<'de, 'a> Deserializer<'de> for PgRowDeserializer<'a> {
-        type Error = DeError;
+    /// Iterates over the raw elements of a Postgres array in binary format, in
+    /// row-major order, yielding `None` for NULL elements
+    struct PgArrayElements<'a> {
+        buf: &'a [u8],
+        remaining: usize,
+    }
 
-        fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
-        where
-            V: Visitor<'de>,
-        {
-            let raw_value = self.row.try_get_raw(0).map_err(DeError::custom)?;
+    impl<'a> PgArrayElements<'a> {
+        /// Reads the array's header, returning its elements and the length of
+        /// each of its dimensions
+        fn new(mut buf: &'a [u8]) -> Result<(Self, Vec<usize>), DeserializeError> {
+            // Header: number of dimensions, has-null flag and element type OID,
+            // then the length and lower bound of each dimension
+            let ndim = read_i32(&mut buf)?;
+            let _flags = read_i32(&mut buf)?;
+            let _element_oid = read_i32(&mut buf)?;
 
-            if raw_value.is_null() {
-                visitor.visit_none()
+            let dims = (0..ndim)
+                .map(|_| {
+                    let len = read_i32(&mut buf)?;
+                    let _lower_bound = read_i32(&mut buf)?;
+
+                    usize::try_from(len).map_err(|_| {
+                        DeserializeError::custom(format!("invalid array length {len}"))
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let remaining = if dims.is_empty() {
+                0
             } else {
-                visitor.visit_some(self)
-            }
-        }
+                dims.iter().product()
+            };
 
-        fn deserialize_newtype_struct<V>(
-            self,
-            _name: &'static str,
-            visitor: V,
-        ) -> Result<V::Value, Self::Error>
-        where
-            V: Visitor<'de>,
-        {
-            visitor.visit_newtype_struct(self)
+            Ok((PgArrayElements { buf, remaining }, dims))
         }
+    }
 
-        fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
-        where
-            V: Visitor<'de>,
-        {
-            match self.row.columns().len() {
-                0 => return visitor.visit_unit(),
-                1 => {}
-                _n => {
-                    return self.deserialize_seq(visitor);
-                }
-            };
+    /// The number of dimensions of a binary-format array value
+    pub(crate) fn array_ndim(value: &PgValueRef<'_>) -> Option<i32> {
+        let mut bytes = value.as_bytes().ok()?;
 
-            let raw_value = self.row.try_get_raw(self.index).map_err(DeError::custom)?;
-            let type_info = raw_value.type_info();
-            let type_name = type_info.name();
+        read_i32(&mut bytes).ok()
+    }
 
-            if raw_value.is_null() {
-                return visitor.visit_none();
-            }
+    impl<'a> Iterator for PgArrayElements<'a> {
+        type Item = Result<Option<&'a [u8]>, DeserializeError>;
 
-            // If this is a BOOL[], TEXT[], etc
-            if type_name.ends_with("[]") {
-                return self.deserialize_seq(visitor);
+        fn next(&mut self) -> Option<Self::Item> {
+            if self.remaining == 0 {
+                return None;
             }
+            self.remaining -= 1;
 
-            // Direct all "basic" types down to `PgValueDeserializer`
-            let deserializer = PgValueDeserializer { value: raw_value };
-
-            deserializer.deserialize_any(visitor)
-        }
+            let element = read_i32(&mut self.buf).and_then(|len| {
+                // A length of -1 marks a NULL element
+                let Ok(len) = usize::try_from(len) else {
+                    return Ok(None);
+                };
 
-        /// We treat the row as a map (each column is a key/value pair)
-        fn deserialize_map<V>(self, visitor: V) -> Result<V::Value, Self::Error>
-        where
-            V: Visitor<'de>,
-        {
-            visitor.visit_map(PgRowMapAccess {
-                deserializer: self,
-                num_cols: self.row.columns().len(),
-            })
-        }
+                if self.buf.len() < len {
+                    return Err(DeserializeError::custom("unexpected end of array data"));
+                }
 
-        fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value, Self::Error>
-        where
-            V: Visitor<'de>,
-        {
-            let raw_value = self.row.try_get_raw(self.index).map_err(DeError::custom)?;
-            let type_info = raw_value.type_info();
-            let type_name = type_info.name();
+                let (element, rest) = self.buf.split_at(len);
+                self.buf = rest;
 
-            match type_name {
-                "TEXT[]" | "VARCHAR[]" => {
-                    let seq_access = PgArraySeqAccess::<String>::new(raw_value)?;
-                    visitor.visit_seq(seq_access)
-                }
-                "INT4[]" => {
-                    let seq_access = PgArraySeqAccess::<i32>::new(raw_value)?;
-                    visitor.visit_seq(seq_access)
-                }
-                "JSON[]" | "JSONB[]" => {
-                    let seq_access = PgArraySeqAccess::<PgJson>::new(raw_value)?;
-                    visitor.visit_seq(seq_access)
-                }
-                "BOOL[]" => {
-                    let seq_access = PgArraySeqAccess::<bool>::new(raw_value)?;
-                    visitor.visit_seq(seq_access)
-                }
-                _ => {
-                    let seq_access = PgRowSeqAccess {
-                        deserializer: self,
-                        num_cols: self.row.columns().len(),
-                    };
+                Ok(Some(element))
+            });
 
-                    visitor.visit_seq(seq_access)
-                }
-            }
+            Some(element)
         }
+    }
 
-        fn deserialize_tuple<V>(self, _len: usize, visitor: V) -> Result<V::Value, Self::Error>
-        where
-            V: Visitor<'de>,
-        {
-            self.deserialize_seq(visitor)
-        }
+    fn read_i32(buf: &mut &[u8]) -> Result<i32, DeserializeError> {
+        let Some((head, rest)) = buf.split_first_chunk::<4>() else {
+            return Err(DeserializeError::custom("unexpected end of array data"));
+        };
+        *buf = rest;
 
-        fn deserialize_struct<V>(
-            self,
-            _name: &'static str,
-            fields: &'static [&'static str],
-            visitor: V,
-        ) -> Result<V::Value, Self::Error>
-        where
-            V: Visitor<'de>,
-        {
-            let raw_value = self.row.try_get_raw(self.index).map_err(DeError::custom)?;
-            let type_info = raw_value.type_info();
-            let type_name = type_info.name();
+        Ok(i32::from_be_bytes(*head))
+    }
 
-            if type_name == "JSON" || type_name == "JSONB" {
-                let value = decode_raw_pg::<PgJson>(raw_value).map_err(|err| {
-                    DeError::custom(format!("Failed to decode JSON/JSONB: {err}"))
-                })?;
+    /// Array element types that can be decoded directly from their binary
+    /// representation, without going through `sqlx::Decode`
+    pub trait PgArrayElement<'a>: Sized {
+        fn decode_element(bytes: &'a [u8]) -> Result<Self, DeserializeError>;
+    }
 
-                if let serde_json::Value::Object(ref obj) = value.0 {
-                    if fields.len() == 1 {
-                        // If there's only one expected field, check if the object already contains it.
-                        if obj.contains_key(fields[0]) {
-                            // If so, we can deserialize directly.
-                            return value.into_deserializer().deserialize_any(visitor);
-                        } else {
-                            // Otherwise, wrap the object in a new map keyed by that field name.
-                            let mut map = serde_json::Map::new();
-                            map.insert(fields[0].to_owned(), value.0);
-                            return map
-                                .into_deserializer()
-                                .deserialize_any(visitor)
-                                .map_err(DeError::custom);
-                        }
-                    } else {
-                        // For multiple expected fields, ensure the JSON object already contains all of them.
-                        if fields.iter().all(|&field| obj.contains_key(field)) {
-                            return value.into_deserializer().deserialize_any(visitor);
-                        } else {
-                            return Err(DeError::custom(format!(
-                                "JSON object missing expected keys: expected {:?}, found keys {:?}",
-                                fields,
-                                obj.keys().collect::<Vec<_>>()
-                            )));
-                        }
+    macro_rules! impl_pg_array_element_be_bytes {
+        ($($ty:ty),*) => {
+            $(
+                impl<'a> PgArrayElement<'a> for $ty {
+                    fn decode_element(bytes: &'a [u8]) -> Result<Self, DeserializeError> {
+                        let bytes = bytes.try_into().map_err(|_| {
+                            DeserializeError::custom(format!(
+                                "invalid length {} for {} array element",
+                                bytes.len(),
+                                std::any::type_name::<$ty>()
+                            ))
+                        })?;
+
+                        Ok(<$ty>::from_be_bytes(bytes))
                     }
-                } else {
-                    // For non-object JSON values, delegate directly.
-                    return value.into_deserializer().deserialize_any(visitor);
                 }
-            }
+            )*
+        };
+    }
+
+    impl_pg_array_element_be_bytes!(i16, i32, i64, f32, f64);
 
-            // Fallback for non-JSON types.
-            self.deserialize_map(visitor)
+    /// TEXT, VARCHAR, BPCHAR and NAME elements are sent as their UTF-8 text
+    impl<'a> PgArrayElement<'a> for &'a str {
+        fn decode_element(bytes: &'a [u8]) -> Result<Self, DeserializeError> {
+            std::str::from_utf8(bytes).map_err(DeserializeError::custom)
         }
+    }
 
-        // For other types, forward to deserialize_any.
-        forward_to_deserialize_any! {
-            bool i8 i16 i32 i64 u8 u16 u32 u64 f32 f64 char str string
-            bytes byte_buf unit unit_struct
-            tuple_struct enum identifier ignored_any
+    impl<'a> PgArrayElement<'a> for bool {
+        fn decode_element(bytes: &'a [u8]) -> Result<Self, DeserializeError> {
+            match bytes {
+                [byte] => Ok(*byte != 0),
+                _ => Err(DeserializeError::custom(format!(
+                    "invalid length {} for bool array element",
+                    bytes.len()
+                ))),
+            }
         }
     }
 
-    /// An "inner" deserializer
-    #[derive(Clone)]
-    pub(crate) struct PgValueDeserializer<'a> {
-        pub(crate) value: PgValueRef<'a>,
+    /// Yet another deserializer, this time to handles Options
+    struct PgArrayElementDeserializer<T> {
+        pub value: Option<T>,
     }
 
-    impl<'de, 'a> Deserializer<'de> for PgValueDeserializer<'a> {
-        type Error = DeError;
+    impl<T> PgArrayElementDeserializer<T> {
+        fn into_element(self) -> Result<T, DeserializeError> {
+            self.value.ok_or_else(|| {
+                DeserializeError::custom("unexpected null in non-optional array element")
+            })
+        }
+    }
+
+    /// Hands the hint over to the element's own deserializer, so that e.g. NUMERIC
+    /// elements know an `f64` was asked for
+    macro_rules! forward_to_element {
+        ($($method:ident$(($($arg:ident: $ty:ty),*))?)*) => {
+            $(
+                fn $method<V>(self, $($($arg: $ty,)*)? visitor: V) -> Result<V::Value, Self::Error>
+                where
+                    V: Visitor<'de>,
+                {
+                    self.into_element()?
+                        .into_deserializer()
+                        .$method($($($arg,)*)? visitor)
+                }
+            )*
+        };
+    }
+
+    impl<'de, T> de::Deserializer<'de> for PgArrayElementDeserializer<T>
+    where
+        T: IntoDeserializer<'de, DeserializeError>,
+    {
+        type Error = DeserializeError;
 
         fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
         where
             V: Visitor<'de>,
         {
-            if self.value.is_null() {
-                visitor.visit_none()
-            } else {
-                visitor.visit_some(self)
+            match self.value {
+                Some(v) => visitor.visit_some(v.into_deserializer()),
+                None => visitor.visit_none(),
             }
         }
 
+        /// Self-describing targets, e.g. `serde_json::Value`, accept NULL elements
         fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
         where
             V: Visitor<'de>,
         {
-            if self.value.is_null() {
-                return visitor.visit_none();
+            match self.value {
+                Some(v) => v.into_deserializer().deserialize_any(visitor),
+                None => visitor.visit_none(),
             }
-            let type_info = self.value.type_info();
+        }
 
-            let type_name = type_info.name();
+        forward_to_element! {
+            deserialize_bool deserialize_i8 deserialize_i16
+            deserialize_i32 deserialize_i64 deserialize_i128 deserialize_u8
+            deserialize_u16 deserialize_u32 deserialize_u64 deserialize_u128
+            deserialize_f32 deserialize_f64 deserialize_char deserialize_str
+            deserialize_string deserialize_bytes deserialize_byte_buf deserialize_seq
+            deserialize_map deserialize_identifier
+            deserialize_unit_struct(name: &'static str)
+            deserialize_newtype_struct(name: &'static str)
+            deserialize_tuple(len: usize)
+            deserialize_tuple_struct(name: &'static str, len: usize)
+            deserialize_struct(name: &'static str, fields: &'static [&'static str])
+            deserialize_enum(name: &'static str, variants: &'static [&'static str])
+        }
 
-            match type_name {
-                "FLOAT4" => {
-                    let v = decode_raw_pg::<f32>(self.value)?;
-                    visitor.visit_f32(v)
-                }
-                "FLOAT8" => {
-                    let v = decode_raw_pg::<f64>(self.value)?;
-                    visitor.visit_f64(v)
-                }
-                "NUMERIC" => {
-                    let numeric = decode_raw_pg::<rust_decimal::Decimal>(self.value)?;
+        forward_to_deserialize_any! {
+            unit ignored_any
+        }
+    }
+}
 
-                    let num: f64 = numeric
-                        .try_into()
-                        .map_err(|_| DeError::custom("Failed to parse Decimal as f64"))?;
+mod map_access {
+    use std::borrow::Cow;
+    use std::fmt;
 
-                    visitor.visit_f64(num)
-                }
-                "INT8" => {
-                    let v = decode_raw_pg::<i64>(self.value)?;
-                    visitor.visit_i64(v)
-                }
-                "INT4" => {
-                    let v = decode_raw_pg::<i32>(self.value)?;
-                    visitor.visit_i32(v)
-                }
-                "INT2" => {
-                    let v = decode_raw_pg::<i16>(self.value)?;
-                    visitor.visit_i16(v)
-                }
-                "BOOL" => {
-                    let v = decode_raw_pg::<bool>(self.value)?;
-                    visitor.visit_bool(v)
-                }
-                "DATE" => {
-                    let date = decode_raw_pg::<chrono::NaiveDate>(self.value)?;
-                    visitor.visit_string(date.to_string())
-                }
-                "TIME" | "TIMETZ" => {
-                    let time = decode_raw_pg::<chrono::NaiveTime>(self.value)?;
-                    visitor.visit_string(time.to_string())
-                }
-                "TIMESTAMP" | "TIMESTAMPTZ" => {
-                    let ts = decode_raw_pg::<chrono::DateTime<chrono::FixedOffset>>(self.value)?;
-                    visitor.visit_string(ts.to_rfc3339())
-                }
-                "UUID" => {
-                    let uuid = decode_raw_pg::<uuid::Uuid>(self.value)?;
-                    visitor.visit_string(uuid.to_string())
-                }
-                "BYTEA" => {
-                    let bytes = decode_raw_pg::<&[u8]>(self.value)?;
-                    visitor.visit_bytes(bytes)
-                }
-                "INTERVAL" => {
-                    let pg_interval =
-                        decode_raw_pg::<sqlx::postgres::types::PgInterval>(self.value)?;
-                    let secs = pg_interval.microseconds / 1_000_000;
-                    let nanos = (pg_interval.microseconds % 1_000_000) * 1000;
-                    let days_duration = chrono::Duration::days(pg_interval.days as i64);
-                    let duration = chrono::Duration::seconds(secs)
-                        + chrono::Duration::nanoseconds(nanos)
-                        + days_duration;
-                    visitor.visit_string(duration.to_string())
-                }
-                "CHAR" | "TEXT" => {
-                    let s = decode_raw_pg::<String>(self.value)?;
-                    visitor.visit_string(s)
-                }
-                "JSON" | "JSONB" => {
-                    let value = decode_raw_pg::<PgJson>(self.value)?;
+    use serde::de::{self, IntoDeserializer, MapAccess};
+    use serde::forward_to_deserialize_any;
 
-                    value.into_deserializer().deserialize_any(visitor)
-                }
-                _other => {
-                    let as_string = decode_raw_pg::<String>(self.value.clone())?;
-                    visitor.visit_string(as_string)
-                }
-            }
-        }
+    use sqlx::{Column, Row};
 
-        // For other types, forward to deserialize_any.
-        forward_to_deserialize_any! {
-            bool i8 i16 i32 i64 u8 u16 u32 u64 f32 f64 char str string
-            bytes byte_buf unit unit_struct newtype_struct struct
-            tuple_struct enum identifier ignored_any tuple seq map
+    use crate::deserializers::PgRowDeserializer;
+    use crate::DeserializeError;
+
+    /// Yields each column as a key/value pair. Keys are the column names exactly as
+    /// Postgres returns them, so serde's field matching (`rename`, `alias`) applies
+    pub(crate) struct PgRowMapAccess<'a> {
+        pub(crate) deserializer: PgRowDeserializer<'a>,
+        pub(crate) num_cols: usize,
+        /// The struct's field names, when deserializing into a struct
+        pub(crate) fields: &'static [&'static str],
+        /// Length of the `_N_` tuple element prefix to strip from column names
+        pub(crate) prefix_len: usize,
+    }
+
+    impl fmt::Debug for PgRowMapAccess<'_> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let done = &self.deserializer.row.columns()[..self.deserializer.index];
+
+            f.debug_struct("PgRowMapAccess")
+                .field(
+                    "columns_done",
+                    &done.iter().map(Column::name).collect::<Vec<_>>(),
+                )
+                .field("num_cols", &self.num_cols)
+                .finish_non_exhaustive()
         }
     }
-}
 
-mod json {
-    use serde::{
-        de::{self, value::Error as DeError, Deserializer, Error, IntoDeserializer},
-        forward_to_deserialize_any,
-    };
-    use serde_json::Value;
-    use sqlx::{
-        postgres::{PgTypeInfo, PgValueRef},
-        Postgres, TypeInfo, ValueRef,
-    };
+    impl<'de, 'a> MapAccess<'de> for PgRowMapAccess<'a> {
+        type Error = DeserializeError;
 
-    /// Decodes Postgres' JSON or JSONB into serde_json::Value
-    #[derive(Debug)]
-    pub(crate) struct PgJson(pub(crate) serde_json::Value);
+        fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+        where
+            K: de::DeserializeSeed<'de>,
+        {
+            let config = self.deserializer.config;
 
-    impl<'a> sqlx::Decode<'a, sqlx::Postgres> for PgJson {
-        fn decode(value: PgValueRef<'a>) -> Result<Self, sqlx::error::BoxDynError> {
-            let is_jsonb = match value.type_info().name() {
-                "JSON" => false,
-                "JSONB" => true,
-                other => unreachable!("Got {other} in PgJson"),
-            };
+            while self.deserializer.index < self.num_cols {
+                let col_name = &self.deserializer.row.columns()[self.deserializer.index].name()
+                    [self.prefix_len..];
+                let mut col_name = match self.deserializer.rename {
+                    Some(rename) => Cow::Owned(rename(col_name)),
+                    None => Cow::Borrowed(col_name),
+                };
+                if config.snake_to_camel_columns {
+                    col_name = Cow::Owned(snake_to_camel(&col_name));
+                }
 
-            let mut bytes = value.as_bytes()?;
+                // Use the column name as the key, or the field it matches when
+                // ignoring case
+                let key = if config.case_insensitive_columns {
+                    self.fields
+                        .iter()
+                        .copied()
+                        .find(|field| field.eq_ignore_ascii_case(&col_name))
+                        .unwrap_or(&col_name)
+                } else {
+                    &col_name
+                };
 
-            // For JSONB, the first byte is a version (should be 1)
-            if is_jsonb {
-                if bytes.is_empty() || bytes[0] != 1 {
-                    return Err("invalid JSONB header".into());
+                // Maps have no fields, and take every column
+                let is_unknown = !self.fields.is_empty() && !self.fields.contains(&key);
+                if config.skip_unknown_columns && is_unknown {
+                    self.deserializer.index += 1;
+                    continue;
                 }
 
-                // Skip the version byte
-                bytes = &bytes[1..]
-            };
+                return seed.deserialize(key.into_deserializer()).map(Some);
+            }
 
-            let value = serde_json::from_slice(bytes)?;
+            Ok(None)
+        }
 
-            Ok(PgJson(value))
+        fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+        where
+            V: de::DeserializeSeed<'de>,
+        {
+            let pg_type_deserializer = self.deserializer.value_deserializer()?;
+
+            self.deserializer.index += 1;
+
+            seed.deserialize(pg_type_deserializer)
         }
     }
 
-    impl sqlx::Type<Postgres> for PgJson {
-        fn type_info() -> PgTypeInfo {
-            PgTypeInfo::with_name("JSON")
+    /// `total_exec_time` to `totalExecTime`, matching serde's `rename_all = "camelCase"`.
+    /// Leading underscores are kept
+    fn snake_to_camel(name: &str) -> String {
+        let mut camel = String::with_capacity(name.len());
+        let mut upper_next = false;
+
+        for c in name.chars() {
+            if c == '_' && !camel.trim_start_matches('_').is_empty() {
+                upper_next = true;
+            } else if upper_next {
+                camel.extend(c.to_uppercase());
+                upper_next = false;
+            } else {
+                camel.push(c);
+            }
         }
+
+        camel
     }
 
-    pub struct PgJsonDeserializer {
-        value: Value,
+    /// The columns of one tuple element, e.g. `_0_one` and `_0_two`, read as a
+    /// map (or struct) keyed by the column names without their `_N_` prefix
+    pub(crate) struct PgColumnGroupDeserializer<'a> {
+        pub(crate) deserializer: PgRowDeserializer<'a>,
+        /// One past the group's last column
+        pub(crate) end: usize,
+        pub(crate) prefix_len: usize,
     }
 
-    impl<'de> Deserializer<'de> for PgJsonDeserializer {
-        type Error = DeError;
+    impl<'de, 'a> de::Deserializer<'de> for PgColumnGroupDeserializer<'a> {
+        type Error = DeserializeError;
 
         fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
         where
             V: de::Visitor<'de>,
         {
-            // Delegate to serde_json::Value's own Deserializer
-            self.value.deserialize_any(visitor).map_err(DeError::custom)
+            self.deserialize_struct("", &[], visitor)
         }
 
-        forward_to_deserialize_any! {
-            bool i8 i16 i32 i64 u8 u16 u32 u64 f32 f64 char str string
-            bytes byte_buf option unit unit_struct newtype_struct seq tuple
-            tuple_struct map struct enum identifier ignored_any
-        }
-    }
+        fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: de::Visitor<'de>,
+        {
+            visitor.visit_some(self)
+        }
+
+        fn deserialize_struct<V>(
+            self,
+            _name: &'static str,
+            fields: &'static [&'static str],
+            visitor: V,
+        ) -> Result<V::Value, Self::Error>
+        where
+            V: de::Visitor<'de>,
+        {
+            visitor.visit_map(PgRowMapAccess {
+                deserializer: self.deserializer,
+                num_cols: self.end,
+                fields,
+                prefix_len: self.prefix_len,
+            })
+        }
+
+        forward_to_deserialize_any! {
+            bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+            bytes byte_buf unit unit_struct newtype_struct seq tuple
+            tuple_struct map enum identifier ignored_any
+        }
+    }
+}
+
+mod deserializers {
+    use std::fmt;
+
+    #[cfg(all(feature = "rust_decimal", not(feature = "bigdecimal")))]
+    use crate::array_elements::PgDecimal;
+    #[cfg(feature = "ltree")]
+    use crate::array_elements::PgLTreeText;
+    #[cfg(not(any(feature = "rust_decimal", feature = "bigdecimal")))]
+    use crate::array_elements::PgNumeric;
+    #[cfg(feature = "bigdecimal")]
+    use crate::array_elements::PgNumericText;
+    use crate::array_elements::{
+        interval_to_string, naive_timestamp_to_string, PgBits, PgBytea, PgDate, PgIntervalWrapper,
+        PgLsn, PgOid, PgTime, PgTimeTz, PgTimestamp, PgTimestampTz, PgUuid, PgXid,
+        INTERVAL_DAYS_PER_MONTH,
+    };
+    use crate::decode_raw_pg;
+    use crate::dispatch::VisitorSlot;
+    #[cfg(feature = "geometry")]
+    use crate::geometry::PgGeometry;
+    use crate::hstore::PgHstoreDeserializer;
+    use crate::json::PgJson;
+    use crate::map_access::PgRowMapAccess;
+    use crate::pg_type_compatibility::{is_compatible, Compatibility};
+    use crate::range::PgRangeValue;
+    use crate::record::PgRecordDeserializer;
+    use crate::seq_access::{
+        array_ndim, tuple_column_groups, PgArraySeqAccess, PgArraySeqAccessRef,
+        PgColumnGroupSeqAccess, PgRowSeqAccess, PgValueSeqAccess,
+    };
+    use crate::tsvector::PgTsVector;
+    use crate::{DeserializeError, DeserializerConfig, TimestampFormat};
+    use serde::de::value::{MapDeserializer, SeqDeserializer, StringDeserializer};
+    use serde::de::{Deserializer, Error as _, IntoDeserializer, Visitor};
+    use serde::forward_to_deserialize_any;
+    use serde::Deserialize;
+    use sqlx::postgres::types::{Oid, PgHstore, PgInterval, PgMoney, PgPoint};
+    #[cfg(feature = "geometry")]
+    use sqlx::postgres::types::{PgBox, PgCircle, PgLSeg, PgLine, PgPath, PgPolygon};
+    use sqlx::postgres::{PgRow, PgValue, PgValueFormat, PgValueRef};
+    use sqlx::types::ipnetwork::IpNetwork;
+    use sqlx::{Column, Row, TypeInfo, Value, ValueRef};
+
+    /// A [`Deserializer`] over a whole [`PgRow`], as used by [`crate::from_pg_row`]
+    ///
+    /// Useful for hand-written `Deserialize` impls, or to read only some of a
+    /// row's columns:
+    ///
+    /// ```
+    /// use serde::de::{Deserialize, Deserializer, MapAccess, Visitor};
+    /// use serde_sqlx::{DeserializeError, PgRowDeserializer};
+    /// use sqlx::postgres::PgRow;
+    ///
+    /// /// Every column of a row, as `name=value` strings
+    /// struct Pairs(Vec<String>);
+    ///
+    /// impl<'de> Deserialize<'de> for Pairs {
+    ///     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    ///         struct PairsVisitor;
+    ///
+    ///         impl<'de> Visitor<'de> for PairsVisitor {
+    ///             type Value = Pairs;
+    ///
+    ///             fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    ///                 f.write_str("a row")
+    ///             }
+    ///
+    ///             fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Pairs, A::Error> {
+    ///                 let mut pairs = Vec::new();
+    ///                 while let Some((name, value)) = map.next_entry::<String, String>()? {
+    ///                     pairs.push(format!("{name}={value}"));
+    ///                 }
+    ///                 Ok(Pairs(pairs))
+    ///             }
+    ///         }
+    ///
+    ///         deserializer.deserialize_map(PairsVisitor)
+    ///     }
+    /// }
+    ///
+    /// /// Skips the row's leading `id` column
+    /// fn pairs_after_id(row: &PgRow) -> Result<Pairs, DeserializeError> {
+    ///     Pairs::deserialize(PgRowDeserializer::builder().with_column_offset(1).build(row))
+    /// }
+    /// ```
+    #[derive(Clone, Copy)]
+    pub struct PgRowDeserializer<'a> {
+        pub(crate) row: &'a PgRow,
+        pub(crate) index: usize,
+        pub(crate) config: &'a DeserializerConfig,
+        /// Applied to each column name before it's matched to a field
+        pub(crate) rename: Option<&'a dyn Fn(&str) -> String>,
+    }
+
+    /// Builds a [`PgRowDeserializer`], see [`PgRowDeserializer::builder`]
+    #[derive(Clone, Copy)]
+    pub struct PgRowDeserializerBuilder<'a> {
+        config: &'a DeserializerConfig,
+        column_offset: usize,
+        rename: Option<&'a dyn Fn(&str) -> String>,
+    }
+
+    /// Shows where in the row deserialization is, not the row's values
+    impl fmt::Debug for PgRowDeserializer<'_> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("PgRowDeserializer")
+                .field("columns", &self.row.columns().len())
+                .field("index", &self.index)
+                .finish_non_exhaustive()
+        }
+    }
+
+    impl fmt::Debug for PgRowDeserializerBuilder<'_> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("PgRowDeserializerBuilder")
+                .field("config", self.config)
+                .field("column_offset", &self.column_offset)
+                .field("rename", &self.rename.map(|_| "Fn(&str) -> String"))
+                .finish()
+        }
+    }
+
+    impl Default for PgRowDeserializerBuilder<'_> {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl<'a> PgRowDeserializerBuilder<'a> {
+        pub fn new() -> Self {
+            PgRowDeserializerBuilder {
+                config: DeserializerConfig::default_ref(),
+                column_offset: 0,
+                rename: None,
+            }
+        }
+
+        /// Start at the given column, leaving out the ones before it
+        pub fn with_column_offset(mut self, column_offset: usize) -> Self {
+            self.column_offset = column_offset;
+            self
+        }
+
+        pub fn with_config(mut self, config: &'a DeserializerConfig) -> Self {
+            self.config = config;
+            self
+        }
+
+        /// Rename each column before matching it to a field, e.g. to strip a `tbl_` prefix
+        pub fn with_rename(mut self, rename: &'a dyn Fn(&str) -> String) -> Self {
+            self.rename = Some(rename);
+            self
+        }
+
+        pub fn build(self, row: &'a PgRow) -> PgRowDeserializer<'a> {
+            PgRowDeserializer {
+                row,
+                index: self.column_offset,
+                config: self.config,
+                rename: self.rename,
+            }
+        }
+    }
+
+    impl<'a> PgRowDeserializer<'a> {
+        pub fn new(row: &'a PgRow) -> Self {
+            Self::builder().build(row)
+        }
+
+        pub fn with_config(row: &'a PgRow, config: &'a DeserializerConfig) -> Self {
+            Self::builder().with_config(config).build(row)
+        }
+
+        pub fn builder() -> PgRowDeserializerBuilder<'a> {
+            PgRowDeserializerBuilder::new()
+        }
+
+        /// Number of columns in the row, including any skipped by a column offset
+        pub fn column_count(&self) -> usize {
+            self.row.columns().len()
+        }
+
+        /// Index of the column deserialization starts from
+        pub fn current_index(&self) -> usize {
+            self.index
+        }
+
+        /// Name of the column at [`Self::current_index`], or `None` past the last one
+        pub fn current_column_name(&self) -> Option<&'a str> {
+            self.row
+                .columns()
+                .get(self.index)
+                .map(|column| column.name())
+        }
+
+        /// Number of columns from the current one to the end of the row
+        fn remaining_columns(&self) -> usize {
+            self.row.columns().len().saturating_sub(self.index)
+        }
+
+        /// Deserializer for the value of the current column
+        pub(crate) fn value_deserializer(
+            &self,
+        ) -> Result<PgValueDeserializer<'a>, DeserializeError> {
+            let value = self
+                .row
+                .try_get_raw(self.index)
+                .map_err(DeserializeError::custom)?;
+
+            Ok(PgValueDeserializer {
+                value,
+                column: Some(self.row.columns()[self.index].name()),
+                config: self.config,
+            })
+        }
+
+        #[allow(unused)]
+        pub fn is_json(&self) -> bool {
+            self.row
+                .try_get_raw(self.index)
+                .is_ok_and(|value| matches!(value.type_info().name(), "JSON" | "JSONB"))
+        }
+
+        /// Whether the row is a single `hstore`, range, INTERVAL or record column, which then
+        /// holds the map's (or struct's) entries rather than being one of them
+        fn is_lone_map_column(&self, fields: &[&str]) -> bool {
+            let columns = &self.row.columns()[self.index.min(self.row.columns().len())..];
+
+            columns.len() == 1
+                && !fields.contains(&columns[0].name())
+                && (matches!(
+                    columns[0].type_info().name(),
+                    "hstore"
+                        | "INTERVAL"
+                        | "INT4RANGE"
+                        | "INT8RANGE"
+                        | "NUMRANGE"
+                        | "DATERANGE"
+                        | "TSRANGE"
+                        | "TSTZRANGE"
+                ) || crate::is_pg_record_type(columns[0].type_info()))
+        }
+    }
+
+    /// Primitives are read straight from the value of a single-column row, so
+    /// that `PgValueDeserializer` sees the type hint
+    macro_rules! forward_to_single_column {
+        ($($method:ident)*) => {
+            $(
+                fn $method<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+                where
+                    V: Visitor<'de>,
+                {
+                    if self.remaining_columns() == 1 {
+                        self.value_deserializer()?.$method(visitor)
+                    } else {
+                        self.deserialize_any(visitor)
+                    }
+                }
+            )*
+        };
+    }
+
+    impl<'de, 'a> Deserializer<'de> for PgRowDeserializer<'a> {
+        type Error = DeserializeError;
+
+        fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            // There's no column to be NULL, e.g. `Option<()>` from `SELECT`
+            if self.remaining_columns() == 0 {
+                return visitor.visit_some(self);
+            }
+
+            let raw_value = self
+                .row
+                .try_get_raw(self.index)
+                .map_err(DeserializeError::custom)?;
+
+            if raw_value.is_null() {
+                visitor.visit_none()
+            } else {
+                visitor.visit_some(self)
+            }
+        }
+
+        fn deserialize_newtype_struct<V>(
+            self,
+            _name: &'static str,
+            visitor: V,
+        ) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            visitor.visit_newtype_struct(self)
+        }
+
+        fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            match self.remaining_columns() {
+                0 => return visitor.visit_unit(),
+                1 => {}
+                // Self-describing targets, e.g. `serde_json::Value`, get the columns by name
+                _n => {
+                    return self.deserialize_map(visitor);
+                }
+            };
+
+            let raw_value = self
+                .row
+                .try_get_raw(self.index)
+                .map_err(DeserializeError::custom)?;
+            let type_info = raw_value.type_info();
+
+            if raw_value.is_null() {
+                return visitor.visit_none();
+            }
+
+            // If this is a BOOL[], TEXT[], etc
+            if crate::is_pg_array_type(&type_info) {
+                return self.deserialize_seq(visitor);
+            }
+
+            // Direct all "basic" types down to `PgValueDeserializer`
+            let deserializer = self.value_deserializer()?;
+
+            deserializer.deserialize_any(visitor)
+        }
+
+        /// We treat the row as a map (each column is a key/value pair)
+        fn deserialize_map<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            if self.is_lone_map_column(&[]) {
+                return self.value_deserializer()?.deserialize_map(visitor);
+            }
+
+            visitor.visit_map(PgRowMapAccess {
+                deserializer: self,
+                num_cols: self.row.columns().len(),
+                fields: &[],
+                prefix_len: 0,
+            })
+        }
+
+        fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            let raw_value = self
+                .row
+                .try_get_raw(self.index)
+                .map_err(DeserializeError::custom)?;
+            let type_info = raw_value.type_info();
+            let type_name = type_info.name();
+            pg_log!(trace, "Type: {type_name}");
+
+            let lone_column = self.remaining_columns() == 1;
+
+            // A NULL array, which only an `Option` can hold. Checked before decoding,
+            // which fails on NULLs
+            if lone_column && raw_value.is_null() && crate::is_pg_array_type(&type_info) {
+                let column = self.row.columns()[self.index].name();
+
+                return visitor
+                    .visit_none()
+                    .map_err(|err: DeserializeError| err.in_column(Some(column), type_name));
+            }
+
+            // A lone column can hold the whole sequence. With more columns, each
+            // column is one element, e.g. a `(Vec<i32>, String)` tuple
+            match type_name {
+                // A lone bit string can be read as a sequence of `bool`, a lone
+                // BYTEA as a sequence of `u8` and a lone tsvector as its lexemes
+                "BIT" | "VARBIT" | "BYTEA" | "tsvector" if lone_column => {
+                    self.value_deserializer()?.deserialize_seq(visitor)
+                }
+                // A lone geometric value's coordinates, e.g. a POINT into `(f64, f64)`
+                _ if lone_column && crate::is_pg_geometry_type(type_name) => {
+                    self.value_deserializer()?.deserialize_seq(visitor)
+                }
+                // A lone record's fields, e.g. `SELECT ROW(1, 'a')` into a tuple
+                _ if lone_column && crate::is_pg_record_type(&type_info) => {
+                    self.value_deserializer()?.deserialize_seq(visitor)
+                }
+                _ if lone_column && crate::is_pg_array_type(&type_info) => {
+                    visit_pg_array(raw_value, visitor)
+                }
+                // A lone JSON/JSONB column holding an array, e.g. `SELECT '[1, 2]'::JSONB`
+                "JSON" | "JSONB" if lone_column => {
+                    let value = decode_raw_pg::<PgJson>(raw_value).map_err(|err| {
+                        DeserializeError::custom(format!("Failed to decode JSON/JSONB: {err}"))
+                    })?;
+
+                    if value.as_ref().is_array() {
+                        value.into_deserializer().deserialize_seq(visitor)
+                    } else {
+                        visitor.visit_seq(PgRowSeqAccess {
+                            deserializer: self,
+                            num_cols: 1,
+                        })
+                    }
+                }
+                _ => {
+                    // `_N` column names only group a whole row
+                    if let Some(groups) = tuple_column_groups(self.row).filter(|_| self.index == 0)
+                    {
+                        return visitor.visit_seq(PgColumnGroupSeqAccess {
+                            deserializer: self,
+                            groups: groups.into_iter(),
+                        });
+                    }
+
+                    let seq_access = PgRowSeqAccess {
+                        deserializer: self,
+                        num_cols: self.row.columns().len(),
+                    };
+
+                    visitor.visit_seq(seq_access)
+                }
+            }
+        }
+
+        fn deserialize_tuple<V>(self, _len: usize, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            self.deserialize_seq(visitor)
+        }
+
+        fn deserialize_tuple_struct<V>(
+            self,
+            _name: &'static str,
+            _len: usize,
+            visitor: V,
+        ) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            self.deserialize_seq(visitor)
+        }
+
+        fn deserialize_struct<V>(
+            self,
+            name: &'static str,
+            fields: &'static [&'static str],
+            visitor: V,
+        ) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            if self.is_lone_map_column(fields) {
+                return self
+                    .value_deserializer()?
+                    .deserialize_struct(name, fields, visitor);
+            }
+
+            // A lone JSON/JSONB column that isn't itself one of the fields holds the
+            // struct's fields, e.g. `SELECT row_to_json(t) FROM t`
+            if self.remaining_columns() == 1 && self.is_json() {
+                let column = self.row.columns()[self.index].name();
+
+                if !fields.contains(&column) {
+                    let raw_value = self
+                        .row
+                        .try_get_raw(self.index)
+                        .map_err(DeserializeError::custom)?;
+                    let value = decode_raw_pg::<PgJson>(raw_value).map_err(|err| {
+                        DeserializeError::custom(format!("Failed to decode JSON/JSONB: {err}"))
+                    })?;
+
+                    if let serde_json::Value::Object(obj) = value.as_ref() {
+                        if fields.len() == 1 && !obj.contains_key(fields[0]) {
+                            // The whole object is the struct's only field
+                            let mut map = serde_json::Map::new();
+                            map.insert(fields[0].to_owned(), value.into_inner());
+                            return map
+                                .into_deserializer()
+                                .deserialize_any(visitor)
+                                .map_err(DeserializeError::custom);
+                        }
+                    }
+
+                    // serde reports missing fields and fills in `#[serde(default)]` ones
+                    return value.into_deserializer().deserialize_any(visitor);
+                }
+            }
+
+            // Fallback for non-JSON types: serde matches the column names to `fields`
+            visitor.visit_map(PgRowMapAccess {
+                deserializer: self,
+                num_cols: self.row.columns().len(),
+                fields,
+                prefix_len: 0,
+            })
+        }
+
+        forward_to_single_column! {
+            deserialize_bool deserialize_i8 deserialize_i16 deserialize_i32 deserialize_i64
+            deserialize_u8 deserialize_u16 deserialize_u32 deserialize_u64
+            deserialize_f32 deserialize_f64 deserialize_char deserialize_str
+            deserialize_string deserialize_bytes deserialize_byte_buf
+        }
+
+        // For other types, forward to deserialize_any.
+        fn deserialize_enum<V>(
+            self,
+            name: &'static str,
+            variants: &'static [&'static str],
+            visitor: V,
+        ) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            if self.remaining_columns() == 1 {
+                self.value_deserializer()?
+                    .deserialize_enum(name, variants, visitor)
+            } else {
+                self.deserialize_any(visitor)
+            }
+        }
+
+        forward_to_deserialize_any! {
+            unit unit_struct identifier ignored_any
+        }
+    }
+
+    /// A [`Deserializer`] over a single Postgres value, which the row-level
+    /// deserializers hand each column to
+    ///
+    /// Postgres enums (`CREATE TYPE ... AS ENUM`) are read as their label, into a
+    /// `String` or a Rust enum's unit variant of the same name. Other types without
+    /// dedicated handling are read as their text
+    ///
+    /// Hand-written `Deserialize` impls can pick their columns out of a row
+    /// themselves and read each one through it:
+    ///
+    /// ```
+    /// use serde::de::Error as _;
+    /// use serde::Deserialize;
+    /// use serde_sqlx::{DeserializeError, PgValueDeserializer};
+    /// use sqlx::postgres::PgRow;
+    /// use sqlx::Row;
+    ///
+    /// struct FullName(String);
+    ///
+    /// fn full_name(row: &PgRow) -> Result<FullName, DeserializeError> {
+    ///     let mut parts = Vec::new();
+    ///     for column in ["first_name", "last_name"] {
+    ///         let value = row.try_get_raw(column).map_err(DeserializeError::custom)?;
+    ///         parts.push(String::deserialize(PgValueDeserializer::new(value))?);
+    ///     }
+    ///
+    ///     Ok(FullName(parts.join(" ")))
+    /// }
+    /// ```
+    #[derive(Clone)]
+    pub struct PgValueDeserializer<'a> {
+        pub(crate) value: PgValueRef<'a>,
+        /// Name of the column the value came from, if known
+        pub(crate) column: Option<&'a str>,
+        pub(crate) config: &'a DeserializerConfig,
+    }
+
+    /// `PgValueRef` isn't `Debug`, so only the value's type is shown
+    impl fmt::Debug for PgValueDeserializer<'_> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("PgValueDeserializer")
+                .field("pg_type", &self.value.type_info().name())
+                .field("column", &self.column)
+                .field("is_null", &self.value.is_null())
+                .finish_non_exhaustive()
+        }
+    }
+
+    impl<'a> PgValueDeserializer<'a> {
+        pub fn new(value: PgValueRef<'a>) -> Self {
+            PgValueDeserializer {
+                value,
+                column: None,
+                config: DeserializerConfig::default_ref(),
+            }
+        }
+
+        fn column_name(&self) -> String {
+            self.column.unwrap_or("?").to_owned()
+        }
+
+        /// Decodes the value through sqlx, attributing failures to the column
+        fn decode<T>(&self) -> Result<T, DeserializeError>
+        where
+            T: sqlx::Decode<'a, sqlx::Postgres>,
+        {
+            T::decode(self.value.clone()).map_err(|source| {
+                // Returned to the caller, which may fall back on something else
+                pg_log!(
+                    debug,
+                    "Failed to decode {} value: {:?}",
+                    std::any::type_name::<T>(),
+                    source
+                );
+
+                DeserializeError::DecodeFailure {
+                    column: self.column_name(),
+                    pg_type: self.value.type_info().name().to_owned(),
+                    source,
+                }
+            })
+        }
+
+        /// With `require_safe_coercions` set, rejects any coercion of this value
+        /// into `rust_type` that isn't [`Compatibility::Safe`]
+        fn check_coercion(&self, rust_type: &'static str) -> Result<(), DeserializeError> {
+            if !self.config.require_safe_coercions || self.value.is_null() {
+                return Ok(());
+            }
+
+            let type_info = self.value.type_info();
+            let pg_type = type_info.name();
+
+            // Custom handlers decide for themselves what they emit
+            if self.config.type_dispatch.get(pg_type).is_some() {
+                return Ok(());
+            }
+
+            // Epoch timestamps are plain integers
+            let compatibility = match (pg_type, self.config.timestamp_format) {
+                ("TIMESTAMPTZ", TimestampFormat::UnixSeconds | TimestampFormat::UnixMillis) => {
+                    is_compatible("INT8", rust_type)
+                }
+                // Postgres enums are read as their label
+                _ if crate::is_pg_enum_type(&type_info) => is_compatible("TEXT", rust_type),
+                _ => is_compatible(pg_type, rust_type),
+            };
+
+            match compatibility {
+                Compatibility::Safe => Ok(()),
+                Compatibility::Lossy => Err(DeserializeError::custom(format!(
+                    "refusing lossy coercion from PG type {pg_type} into {rust_type}"
+                ))
+                .in_column(self.column, pg_type)),
+                Compatibility::Incompatible => Err(DeserializeError::TypeMismatch {
+                    column: self.column_name(),
+                    pg_type: pg_type.to_owned(),
+                    target_type: rust_type,
+                }),
+            }
+        }
+
+        /// DATE, TIME, TIMESTAMP and TIMESTAMPTZ values as strings, in the formats
+        /// chrono's types read
+        fn visit_datetime_string<'de, V>(self, visitor: V) -> Result<V::Value, DeserializeError>
+        where
+            V: Visitor<'de>,
+        {
+            match self.value.type_info().name() {
+                "DATE" => {
+                    let date = self.decode::<chrono::NaiveDate>()?;
+                    visitor.visit_string(date.to_string())
+                }
+                "TIME" => {
+                    let time = self.decode::<chrono::NaiveTime>()?;
+                    visitor.visit_string(time.to_string())
+                }
+                "TIMESTAMP" => {
+                    let ts = self.decode::<chrono::NaiveDateTime>()?;
+                    visitor.visit_string(naive_timestamp_to_string(ts))
+                }
+                // Postgres stores TIMESTAMPTZ in UTC, whatever offset it was written with
+                "TIMESTAMPTZ" => {
+                    let ts = self.decode::<chrono::DateTime<chrono::Utc>>()?;
+
+                    match self.config.timestamp_format {
+                        TimestampFormat::Iso8601 => visitor.visit_string(ts.to_rfc3339()),
+                        TimestampFormat::UnixSeconds => visitor.visit_i64(ts.timestamp()),
+                        TimestampFormat::UnixMillis => visitor.visit_i64(ts.timestamp_millis()),
+                    }
+                }
+                other => Err(DeserializeError::custom(format!(
+                    "{other} is not a date or time type"
+                ))),
+            }
+        }
+
+        /// DATE, TIME, TIMESTAMP and TIMESTAMPTZ values in the formats the `time`
+        /// crate's types read, e.g. `2024-01-31 12:30:00.25` for a TIMESTAMP
+        #[cfg(feature = "time")]
+        fn visit_time_value<'de, V>(self, visitor: V) -> Result<V::Value, DeserializeError>
+        where
+            V: Visitor<'de>,
+        {
+            fn to_json<T: serde::Serialize>(value: T) -> Result<PgJson, DeserializeError> {
+                serde_json::to_value(value)
+                    .map(PgJson)
+                    .map_err(DeserializeError::custom)
+            }
+
+            let value = match self.value.type_info().name() {
+                "DATE" => to_json(self.decode::<time::Date>()?)?,
+                "TIME" => to_json(self.decode::<time::Time>()?)?,
+                "TIMESTAMP" => to_json(self.decode::<time::PrimitiveDateTime>()?)?,
+                // Epoch timestamps are the same integers either way
+                "TIMESTAMPTZ" if self.config.timestamp_format == TimestampFormat::Iso8601 => {
+                    to_json(self.decode::<time::OffsetDateTime>()?)?
+                }
+                _ => return self.visit_datetime_string(visitor),
+            };
+
+            value.into_deserializer().deserialize_any(visitor)
+        }
+
+        /// A value of `pg_type` that no custom handler was registered for
+        fn is_builtin(&self, pg_type: &str) -> bool {
+            self.value.type_info().name() == pg_type
+                && self.config.type_dispatch.get(pg_type).is_none()
+        }
+
+        /// An INTERVAL's length in microseconds, counting months as
+        /// [`INTERVAL_DAYS_PER_MONTH`] days
+        fn interval_micros(&self) -> Result<i64, DeserializeError> {
+            const MICROS_PER_DAY: i64 = 86_400_000_000;
+
+            let interval = self.decode::<PgInterval>()?;
+            let days =
+                i64::from(interval.months) * INTERVAL_DAYS_PER_MONTH + i64::from(interval.days);
+
+            days.checked_mul(MICROS_PER_DAY)
+                .and_then(|micros| micros.checked_add(interval.microseconds))
+                .ok_or_else(|| DeserializeError::custom("INTERVAL overflows i64 microseconds"))
+        }
+
+        /// Records are only split into their fields in the binary format, in text
+        /// they're read as a string like `(1,a)`
+        fn is_binary_record(&self) -> bool {
+            !self.value.is_null()
+                && self.value.format() == PgValueFormat::Binary
+                && crate::is_pg_record_type(&self.value.type_info())
+        }
+
+        fn is_json_text_column(&self) -> bool {
+            self.column.is_some_and(|column| {
+                self.config
+                    .text_columns_as_json
+                    .iter()
+                    .any(|json_column| json_column == column)
+            })
+        }
+    }
+
+    /// An owned copy of a single Postgres value.
+    ///
+    /// The raw bytes are copied out of the row, so this is `Clone` and outlives
+    /// the `PgRow` it came from. Useful for retries or multi-pass deserialization,
+    /// where the same value has to be deserialized more than once.
+    #[derive(Clone)]
+    pub struct OwnedPgValue {
+        value: PgValue,
+    }
+
+    impl OwnedPgValue {
+        pub fn new(value: PgValueRef<'_>) -> Self {
+            OwnedPgValue {
+                value: ValueRef::to_owned(&value),
+            }
+        }
+
+        /// Deserializes a copy of the value into any T that implements Deserialize
+        pub fn deserialize<T>(&self) -> Result<T, DeserializeError>
+        where
+            T: for<'de> Deserialize<'de>,
+        {
+            T::deserialize(self.deserializer())
+        }
+
+        pub(crate) fn deserializer(&self) -> PgValueDeserializer<'_> {
+            PgValueDeserializer::new(self.value.as_ref())
+        }
+    }
+
+    /// Visits the elements of a Postgres array value as a sequence
+    pub(crate) fn visit_pg_array<'de, V>(
+        raw_value: PgValueRef<'_>,
+        visitor: V,
+    ) -> Result<V::Value, DeserializeError>
+    where
+        V: Visitor<'de>,
+    {
+        let type_info = raw_value.type_info();
+        let type_name = type_info.name();
+        // Fixed-width elements in binary format can be decoded lazily
+        let is_binary = raw_value.format() == PgValueFormat::Binary;
+
+        // sqlx only decodes one-dimensional arrays, these are read from the raw bytes
+        if is_binary && array_ndim(&raw_value).is_some_and(|ndim| ndim > 1) {
+            return match type_name {
+                "INT2[]" => visitor.visit_seq(PgArraySeqAccessRef::<i16>::new(raw_value)?),
+                "INT4[]" => visitor.visit_seq(PgArraySeqAccessRef::<i32>::new(raw_value)?),
+                "INT8[]" => visitor.visit_seq(PgArraySeqAccessRef::<i64>::new(raw_value)?),
+                "FLOAT4[]" => visitor.visit_seq(PgArraySeqAccessRef::<f32>::new(raw_value)?),
+                "FLOAT8[]" => visitor.visit_seq(PgArraySeqAccessRef::<f64>::new(raw_value)?),
+                "BOOL[]" => visitor.visit_seq(PgArraySeqAccessRef::<bool>::new(raw_value)?),
+                "TEXT[]" | "VARCHAR[]" | "CHAR[]" | "NAME[]" => {
+                    visitor.visit_seq(PgArraySeqAccessRef::<&str>::new(raw_value)?)
+                }
+                other => Err(DeserializeError::custom(format!(
+                    "Multi-dimensional {other} arrays are not supported"
+                ))),
+            };
+        }
+
+        match type_name {
+            "TEXT[]" | "VARCHAR[]" => {
+                let seq_access = PgArraySeqAccess::<String>::new(raw_value)?;
+                visitor.visit_seq(seq_access)
+            }
+            "INT2[]" if is_binary => {
+                let seq_access = PgArraySeqAccessRef::<i16>::new(raw_value)?;
+                visitor.visit_seq(seq_access)
+            }
+            "INT2[]" => {
+                let seq_access = PgArraySeqAccess::<i16>::new(raw_value)?;
+                visitor.visit_seq(seq_access)
+            }
+            "INT4[]" if is_binary => {
+                let seq_access = PgArraySeqAccessRef::<i32>::new(raw_value)?;
+                visitor.visit_seq(seq_access)
+            }
+            "INT4[]" => {
+                let seq_access = PgArraySeqAccess::<i32>::new(raw_value)?;
+                visitor.visit_seq(seq_access)
+            }
+            "INT8[]" if is_binary => {
+                let seq_access = PgArraySeqAccessRef::<i64>::new(raw_value)?;
+                visitor.visit_seq(seq_access)
+            }
+            "INT8[]" => {
+                let seq_access = PgArraySeqAccess::<i64>::new(raw_value)?;
+                visitor.visit_seq(seq_access)
+            }
+            "OID[]" => {
+                let seq_access = PgArraySeqAccess::<PgOid>::new(raw_value)?;
+                visitor.visit_seq(seq_access)
+            }
+            "BIT[]" | "VARBIT[]" => {
+                let seq_access = PgArraySeqAccess::<PgBits>::new(raw_value)?;
+                visitor.visit_seq(seq_access)
+            }
+            "FLOAT4[]" if is_binary => {
+                let seq_access = PgArraySeqAccessRef::<f32>::new(raw_value)?;
+                visitor.visit_seq(seq_access)
+            }
+            "FLOAT4[]" => {
+                let seq_access = PgArraySeqAccess::<f32>::new(raw_value)?;
+                visitor.visit_seq(seq_access)
+            }
+            "FLOAT8[]" if is_binary => {
+                let seq_access = PgArraySeqAccessRef::<f64>::new(raw_value)?;
+                visitor.visit_seq(seq_access)
+            }
+            "FLOAT8[]" => {
+                let seq_access = PgArraySeqAccess::<f64>::new(raw_value)?;
+                visitor.visit_seq(seq_access)
+            }
+            "JSON[]" | "JSONB[]" => {
+                let seq_access = PgArraySeqAccess::<PgJson>::new(raw_value)?;
+                visitor.visit_seq(seq_access)
+            }
+            #[cfg(feature = "geometry")]
+            "POINT[]" => {
+                let seq_access = PgArraySeqAccess::<PgGeometry<PgPoint>>::new(raw_value)?;
+                visitor.visit_seq(seq_access)
+            }
+            #[cfg(feature = "geometry")]
+            "LINE[]" => {
+                let seq_access = PgArraySeqAccess::<PgGeometry<PgLine>>::new(raw_value)?;
+                visitor.visit_seq(seq_access)
+            }
+            #[cfg(feature = "geometry")]
+            "LSEG[]" => {
+                let seq_access = PgArraySeqAccess::<PgGeometry<PgLSeg>>::new(raw_value)?;
+                visitor.visit_seq(seq_access)
+            }
+            #[cfg(feature = "geometry")]
+            "BOX[]" => {
+                let seq_access = PgArraySeqAccess::<PgGeometry<PgBox>>::new(raw_value)?;
+                visitor.visit_seq(seq_access)
+            }
+            #[cfg(feature = "geometry")]
+            "PATH[]" => {
+                let seq_access = PgArraySeqAccess::<PgGeometry<PgPath>>::new(raw_value)?;
+                visitor.visit_seq(seq_access)
+            }
+            #[cfg(feature = "geometry")]
+            "POLYGON[]" => {
+                let seq_access = PgArraySeqAccess::<PgGeometry<PgPolygon>>::new(raw_value)?;
+                visitor.visit_seq(seq_access)
+            }
+            #[cfg(feature = "geometry")]
+            "CIRCLE[]" => {
+                let seq_access = PgArraySeqAccess::<PgGeometry<PgCircle>>::new(raw_value)?;
+                visitor.visit_seq(seq_access)
+            }
+            "UUID[]" => {
+                let seq_access = PgArraySeqAccess::<PgUuid>::new(raw_value)?;
+                visitor.visit_seq(seq_access)
+            }
+            "DATE[]" => {
+                let seq_access = PgArraySeqAccess::<PgDate>::new(raw_value)?;
+                visitor.visit_seq(seq_access)
+            }
+            "TIME[]" => {
+                let seq_access = PgArraySeqAccess::<PgTime>::new(raw_value)?;
+                visitor.visit_seq(seq_access)
+            }
+            "TIMETZ[]" => {
+                let seq_access = PgArraySeqAccess::<PgTimeTz>::new(raw_value)?;
+                visitor.visit_seq(seq_access)
+            }
+            "TIMESTAMP[]" => {
+                let seq_access = PgArraySeqAccess::<PgTimestamp>::new(raw_value)?;
+                visitor.visit_seq(seq_access)
+            }
+            "TIMESTAMPTZ[]" => {
+                let seq_access = PgArraySeqAccess::<PgTimestampTz>::new(raw_value)?;
+                visitor.visit_seq(seq_access)
+            }
+            "INTERVAL[]" => {
+                let seq_access = PgArraySeqAccess::<PgIntervalWrapper>::new(raw_value)?;
+                visitor.visit_seq(seq_access)
+            }
+            "BYTEA[]" => {
+                let seq_access = PgArraySeqAccess::<PgBytea>::new(raw_value)?;
+                visitor.visit_seq(seq_access)
+            }
+            #[cfg(not(any(feature = "rust_decimal", feature = "bigdecimal")))]
+            "NUMERIC[]" => {
+                let seq_access = PgArraySeqAccess::<PgNumeric>::new(raw_value)?;
+                visitor.visit_seq(seq_access)
+            }
+            #[cfg(any(feature = "rust_decimal", feature = "bigdecimal"))]
+            "NUMERIC[]" => {
+                let seq_access = PgArraySeqAccess::<ExactNumeric>::new(raw_value)?;
+                visitor.visit_seq(seq_access)
+            }
+            "INT4RANGE[]" => {
+                let seq_access = PgArraySeqAccess::<PgRangeValue<i32>>::new(raw_value)?;
+                visitor.visit_seq(seq_access)
+            }
+            "INT8RANGE[]" => {
+                let seq_access = PgArraySeqAccess::<PgRangeValue<i64>>::new(raw_value)?;
+                visitor.visit_seq(seq_access)
+            }
+            "NUMRANGE[]" => {
+                let seq_access =
+                    PgArraySeqAccess::<PgRangeValue<rust_decimal::Decimal>>::new(raw_value)?;
+                visitor.visit_seq(seq_access)
+            }
+            "DATERANGE[]" => {
+                let seq_access =
+                    PgArraySeqAccess::<PgRangeValue<chrono::NaiveDate>>::new(raw_value)?;
+                visitor.visit_seq(seq_access)
+            }
+            "TSRANGE[]" => {
+                let seq_access =
+                    PgArraySeqAccess::<PgRangeValue<chrono::NaiveDateTime>>::new(raw_value)?;
+                visitor.visit_seq(seq_access)
+            }
+            "TSTZRANGE[]" => {
+                let seq_access = PgArraySeqAccess::<
+                    PgRangeValue<chrono::DateTime<chrono::FixedOffset>>,
+                >::new(raw_value)?;
+                visitor.visit_seq(seq_access)
+            }
+            "BOOL[]" if is_binary => {
+                let seq_access = PgArraySeqAccessRef::<bool>::new(raw_value)?;
+                visitor.visit_seq(seq_access)
+            }
+            "BOOL[]" => {
+                let seq_access = PgArraySeqAccess::<bool>::new(raw_value)?;
+                visitor.visit_seq(seq_access)
+            }
+            other => Err(DeserializeError::custom(format!(
+                "Unsupported array type {other}"
+            ))),
+        }
+    }
+
+    /// How NUMERIC values are kept exact. `bigdecimal` takes precedence over
+    /// `rust_decimal`, since it handles any precision
+    #[cfg(feature = "bigdecimal")]
+    type ExactNumeric = PgNumericText;
+    #[cfg(all(feature = "rust_decimal", not(feature = "bigdecimal")))]
+    type ExactNumeric = PgDecimal;
+
+    /// Checks the coercion to `$rust_type` before forwarding to `deserialize_any`
+    macro_rules! deserialize_checked {
+        ($($method:ident => $rust_type:literal),* $(,)?) => {
+            $(
+                fn $method<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+                where
+                    V: Visitor<'de>,
+                {
+                    if self.value.is_null() {
+                        return Err(DeserializeError::NullValue {
+                            column: self.column_name(),
+                        });
+                    }
+
+                    self.check_coercion($rust_type)?;
+
+                    // Byte targets, e.g. `bytes::Bytes`, get the bytes as they are
+                    if matches!($rust_type, "bytes" | "byte_buf") && self.is_builtin("BYTEA") {
+                        let column = self.column;
+
+                        return self
+                            .decode::<&[u8]>()
+                            .and_then(|bytes| visitor.visit_bytes(bytes))
+                            .map_err(|err| err.in_column(column, "BYTEA"));
+                    }
+
+                    // Integer targets get an INTERVAL's total microseconds
+                    if matches!($rust_type, "i64" | "u64") && self.is_builtin("INTERVAL") {
+                        let column = self.column;
+
+                        return self
+                            .interval_micros()
+                            .and_then(|micros| visitor.visit_i64(micros))
+                            .map_err(|err| err.in_column(column, "INTERVAL"));
+                    }
+
+                    // A catalog reference is only its OID in the binary format, which is
+                    // no name, so a string target is refused rather than given the number
+                    if matches!($rust_type, "str" | "String")
+                        && (self.is_builtin("regclass") || self.is_builtin("regtype"))
+                        && self.value.format() == PgValueFormat::Binary
+                    {
+                        return Err(DeserializeError::TypeMismatch {
+                            column: self.column_name(),
+                            pg_type: self.value.type_info().name().to_owned(),
+                            target_type: $rust_type,
+                        });
+                    }
+
+                    // Narrower integer columns are widened here, rather than relying on
+                    // the visitor to accept a smaller integer
+                    if matches!($rust_type, "i32" | "i64") && self.is_builtin("INT2") {
+                        let column = self.column;
+
+                        return self
+                            .decode::<i16>()
+                            .and_then(|num| match $rust_type {
+                                "i32" => visitor.visit_i32(num.into()),
+                                _ => visitor.visit_i64(num.into()),
+                            })
+                            .map_err(|err| err.in_column(column, "INT2"));
+                    }
+                    if matches!($rust_type, "i64") && self.is_builtin("INT4") {
+                        let column = self.column;
+
+                        return self
+                            .decode::<i32>()
+                            .and_then(|num| visitor.visit_i64(num.into()))
+                            .map_err(|err| err.in_column(column, "INT4"));
+                    }
+
+                    // String targets, and chrono's types, which read from a string, keep
+                    // the chrono formats. `time`'s types go through `deserialize_any`
+                    #[cfg(feature = "time")]
+                    if matches!($rust_type, "str" | "String")
+                        && ["DATE", "TIME", "TIMESTAMP", "TIMESTAMPTZ"]
+                            .into_iter()
+                            .any(|pg_type| self.is_builtin(pg_type))
+                    {
+                        let column = self.column;
+                        let type_info = self.value.type_info().into_owned();
+
+                        return self
+                            .visit_datetime_string(visitor)
+                            .map_err(|err| err.in_column(column, type_info.name()));
+                    }
+
+                    // Lets the decimal pick its own representation for the target
+                    #[cfg(any(feature = "rust_decimal", feature = "bigdecimal"))]
+                    if self.is_builtin("NUMERIC") {
+                        let column = self.column;
+
+                        return self
+                            .decode::<ExactNumeric>()?
+                            .$method(visitor)
+                            .map_err(|err| err.in_column(column, "NUMERIC"));
+                    }
+
+                    self.deserialize_any(visitor)
+                }
+            )*
+        };
+    }
+
+    impl<'a> PgValueDeserializer<'a> {
+        /// The bulk of `deserialize_any`, which adds the column to any error
+        fn visit_value<'de, V>(self, visitor: V) -> Result<V::Value, DeserializeError>
+        where
+            V: Visitor<'de>,
+        {
+            if self.value.is_null() {
+                return visitor.visit_none();
+            }
+            let type_info = self.value.type_info();
+
+            let type_name = type_info.name();
+
+            // User-registered handlers take precedence over the built-in ones
+            if let Some(handler) = self.config.type_dispatch.get(type_name) {
+                let mut slot = VisitorSlot::new(visitor);
+                handler(self.value.clone(), &mut slot)?;
+                return slot.finish(type_name);
+            }
+
+            if crate::is_pg_array_type(&type_info) {
+                return visit_pg_array(self.value, visitor);
+            }
+
+            if self.is_binary_record() {
+                return PgRecordDeserializer::new(self.value, self.config)?
+                    .deserialize_any(visitor);
+            }
+
+            // A `CREATE TYPE ... AS ENUM` value, read as its label
+            if crate::is_pg_enum_type(&type_info) {
+                let label = self.decode::<String>()?;
+                return visitor.visit_string(label);
+            }
+
+            match type_name {
+                "FLOAT4" => {
+                    let v = self.decode::<f32>()?;
+                    visitor.visit_f32(v)
+                }
+                "FLOAT8" => {
+                    let v = self.decode::<f64>()?;
+                    visitor.visit_f64(v)
+                }
+                #[cfg(any(feature = "rust_decimal", feature = "bigdecimal"))]
+                "NUMERIC" => self.decode::<ExactNumeric>()?.deserialize_any(visitor),
+                #[cfg(not(any(feature = "rust_decimal", feature = "bigdecimal")))]
+                "NUMERIC" => {
+                    use rust_decimal::prelude::ToPrimitive;
+
+                    let numeric = self.decode::<rust_decimal::Decimal>()?;
+
+                    // Whole numbers stay integers, so e.g. a NUMERIC(10, 0) reads into an i64
+                    if numeric.fract().is_zero() {
+                        if let Some(num) = numeric.to_i64() {
+                            return visitor.visit_i64(num);
+                        }
+                        if let Some(num) = numeric.to_u64() {
+                            return visitor.visit_u64(num);
+                        }
+                    }
+
+                    let num: f64 = numeric
+                        .try_into()
+                        .map_err(|_| DeserializeError::custom("Failed to parse Decimal as f64"))?;
+
+                    visitor.visit_f64(num)
+                }
+                "INT8" => {
+                    let v = self.decode::<i64>()?;
+                    visitor.visit_i64(v)
+                }
+                "OID" => {
+                    let oid = self.decode::<Oid>()?;
+                    visitor.visit_u32(oid.0)
+                }
+                // Not a built-in in sqlx, so reported in lowercase
+                "pg_lsn" => {
+                    let lsn = self.decode::<PgLsn>()?;
+                    visitor.visit_u64(lsn.0)
+                }
+                "xid" => {
+                    let xid = self.decode::<PgXid>()?;
+                    visitor.visit_u32(xid.0)
+                }
+                // Catalog references. Only the text format, e.g. from `sqlx::raw_sql`,
+                // carries the object's name. The binary format, which `sqlx::query`
+                // uses, is its OID, so cast it to TEXT in the query for the name
+                "regclass" | "regtype" => match self.value.format() {
+                    PgValueFormat::Text => visitor.visit_string(self.decode::<String>()?),
+                    PgValueFormat::Binary => visitor.visit_u32(self.decode::<PgOid>()?.0),
+                },
+                "MONEY" => {
+                    let money = self.decode::<PgMoney>()?;
+
+                    if self.config.money_as_dollars {
+                        visitor.visit_f64(money.0 as f64 / 100.0)
+                    } else {
+                        visitor.visit_i64(money.0)
+                    }
+                }
+                "INT4" => {
+                    let v = self.decode::<i32>()?;
+                    visitor.visit_i32(v)
+                }
+                "INT2" => {
+                    let v = self.decode::<i16>()?;
+                    visitor.visit_i16(v)
+                }
+                "BOOL" => {
+                    let v = self.decode::<bool>()?;
+                    visitor.visit_bool(v)
+                }
+                #[cfg(not(feature = "time"))]
+                "DATE" | "TIME" | "TIMESTAMP" | "TIMESTAMPTZ" => {
+                    self.visit_datetime_string(visitor)
+                }
+                #[cfg(feature = "time")]
+                "DATE" | "TIME" | "TIMESTAMP" | "TIMESTAMPTZ" => self.visit_time_value(visitor),
+                "TIMETZ" => {
+                    let time =
+                        self.decode::<sqlx::postgres::types::PgTimeTz<
+                            chrono::NaiveTime,
+                            chrono::FixedOffset,
+                        >>()?;
+                    visitor.visit_string(format!("{}{}", time.time, time.offset))
+                }
+                "INT4RANGE" => self
+                    .decode::<PgRangeValue<i32>>()?
+                    .into_deserializer()
+                    .deserialize_any(visitor),
+                "INT8RANGE" => self
+                    .decode::<PgRangeValue<i64>>()?
+                    .into_deserializer()
+                    .deserialize_any(visitor),
+                "NUMRANGE" => self
+                    .decode::<PgRangeValue<rust_decimal::Decimal>>()?
+                    .into_deserializer()
+                    .deserialize_any(visitor),
+                "DATERANGE" => self
+                    .decode::<PgRangeValue<chrono::NaiveDate>>()?
+                    .into_deserializer()
+                    .deserialize_any(visitor),
+                "TSRANGE" => self
+                    .decode::<PgRangeValue<chrono::NaiveDateTime>>()?
+                    .into_deserializer()
+                    .deserialize_any(visitor),
+                "TSTZRANGE" => self
+                    .decode::<PgRangeValue<chrono::DateTime<chrono::FixedOffset>>>()?
+                    .into_deserializer()
+                    .deserialize_any(visitor),
+                "UUID" => {
+                    let uuid = self.decode::<uuid::Uuid>()?;
+                    visitor.visit_string(uuid.to_string())
+                }
+                // hstore comes from an extension, so its type name is reported in lowercase
+                "hstore" => {
+                    let hstore = self.decode::<PgHstore>()?;
+                    PgHstoreDeserializer::new(hstore).deserialize_any(visitor)
+                }
+                // As its text form, or its lexemes when read as a sequence
+                "tsvector" => self
+                    .decode::<PgTsVector>()?
+                    .into_deserializer()
+                    .deserialize_any(visitor),
+                "INET" | "CIDR" => {
+                    let network = self.decode::<IpNetwork>()?;
+
+                    // A full-length netmask is a single host, written without the suffix.
+                    // Like Postgres, CIDR values always keep theirs
+                    let host_prefix = if network.is_ipv4() { 32 } else { 128 };
+
+                    if type_name == "INET" && network.prefix() == host_prefix {
+                        visitor.visit_string(network.ip().to_string())
+                    } else {
+                        visitor.visit_string(network.to_string())
+                    }
+                }
+                // A sequence of `u8`, which self-describing targets like
+                // `serde_json::Value` can hold
+                "BYTEA" => self
+                    .decode::<PgBytea>()?
+                    .into_deserializer()
+                    .deserialize_any(visitor),
+                "BIT" | "VARBIT" => self
+                    .decode::<PgBits>()?
+                    .into_deserializer()
+                    .deserialize_any(visitor),
+                // From the `ltree` extension, so reported in lowercase
+                #[cfg(feature = "ltree")]
+                "ltree" | "lquery" | "ltxtquery" => {
+                    let text = self.decode::<PgLTreeText>()?;
+                    visitor.visit_string(text.0)
+                }
+                #[cfg(feature = "geometry")]
+                "LINE" => self
+                    .decode::<PgGeometry<PgLine>>()?
+                    .into_deserializer()
+                    .deserialize_any(visitor),
+                #[cfg(feature = "geometry")]
+                "LSEG" => self
+                    .decode::<PgGeometry<PgLSeg>>()?
+                    .into_deserializer()
+                    .deserialize_any(visitor),
+                #[cfg(feature = "geometry")]
+                "BOX" => self
+                    .decode::<PgGeometry<PgBox>>()?
+                    .into_deserializer()
+                    .deserialize_any(visitor),
+                #[cfg(feature = "geometry")]
+                "PATH" => self
+                    .decode::<PgGeometry<PgPath>>()?
+                    .into_deserializer()
+                    .deserialize_any(visitor),
+                #[cfg(feature = "geometry")]
+                "POLYGON" => self
+                    .decode::<PgGeometry<PgPolygon>>()?
+                    .into_deserializer()
+                    .deserialize_any(visitor),
+                #[cfg(feature = "geometry")]
+                "CIRCLE" => self
+                    .decode::<PgGeometry<PgCircle>>()?
+                    .into_deserializer()
+                    .deserialize_any(visitor),
+                // What a function returning `void` gives, e.g. `SELECT pg_sleep(0)`
+                "VOID" => visitor.visit_unit(),
+                // `(x, y)`, e.g. into `(f64, f64)`, `[f64; 2]` or a struct
+                "POINT" => {
+                    let point = self.decode::<PgPoint>()?;
+                    SeqDeserializer::new([point.x, point.y].into_iter()).deserialize_any(visitor)
+                }
+                "MACADDR" => {
+                    let mac = self.decode::<[u8; 6]>()?;
+                    visitor.visit_string(mac_to_string(&mac))
+                }
+                "MACADDR8" => {
+                    let mac = self.decode::<[u8; 8]>()?;
+                    visitor.visit_string(mac_to_string(&mac))
+                }
+                "INTERVAL" => {
+                    let pg_interval = self.decode::<PgInterval>()?;
+                    visitor.visit_string(interval_to_string(pg_interval))
+                }
+                "TEXT" | "VARCHAR" if self.is_json_text_column() => {
+                    let s = self.decode::<&str>()?;
+                    let value = serde_json::from_str(s).map_err(|err| {
+                        DeserializeError::custom(format!("Failed to parse TEXT as JSON: {err}"))
+                    })?;
+
+                    PgJson(value).into_deserializer().deserialize_any(visitor)
+                }
+                // BPCHAR is `CHAR(n)`, its blank padding is kept as-is. NAME is the
+                // identifier type used throughout the system catalogs. XML is sent
+                // as its text, and so is the `citext` extension's case-insensitive text
+                "CHAR" | "TEXT" | "BPCHAR" | "NAME" | "xml" | "citext" => {
+                    let s = self.decode::<String>()?;
+                    visitor.visit_string(s)
+                }
+                "JSON" | "JSONB" => {
+                    let value = self.decode::<PgJson>()?;
+
+                    value.into_deserializer().deserialize_any(visitor)
+                }
+                other => {
+                    pg_log!(
+                        debug,
+                        "No dedicated handling for {other}, decoding it as a string"
+                    );
+                    if let Ok(s) =
+                        <String as sqlx::Decode<sqlx::Postgres>>::decode(self.value.clone())
+                    {
+                        return visitor.visit_string(s);
+                    }
+
+                    // Not text, e.g. an unknown type in the binary format. Its raw bytes
+                    // are shown the way Postgres prints a BYTEA, e.g. `\x0102`
+                    pg_log!(
+                        warn,
+                        "{other} can't be decoded as a string, reading its raw bytes as hex"
+                    );
+                    let bytes = self.decode::<&[u8]>()?;
+                    visitor.visit_string(bytes_to_hex(bytes))
+                }
+            }
+        }
+    }
+
+    /// Formats bytes the way Postgres prints a BYTEA, e.g. `\x0102`
+    fn bytes_to_hex(bytes: &[u8]) -> String {
+        let hex: String = bytes.iter().map(|byte| format!("{byte:02x}")).collect();
+        format!("\\x{hex}")
+    }
+
+    /// Formats a MAC address the way Postgres prints it, e.g. `08:00:2b:01:02:03`
+    fn mac_to_string(mac: &[u8]) -> String {
+        mac.iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect::<Vec<_>>()
+            .join(":")
+    }
+
+    impl<'de, 'a> Deserializer<'de> for PgValueDeserializer<'a> {
+        type Error = DeserializeError;
+
+        fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            if self.value.is_null() {
+                return visitor.visit_none();
+            }
+
+            // A JSON `null` is `None` too
+            if self.is_builtin("JSON") || self.is_builtin("JSONB") {
+                let column = self.column;
+                let type_info = self.value.type_info().into_owned();
+
+                return self
+                    .decode::<PgJson>()?
+                    .into_deserializer()
+                    .deserialize_option(visitor)
+                    .map_err(|err| err.in_column(column, type_info.name()));
+            }
+
+            visitor.visit_some(self)
+        }
+
+        fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            let column = self.column;
+            let type_info = self.value.type_info().into_owned();
+
+            self.visit_value(visitor)
+                .map_err(|err| err.in_column(column, type_info.name()))
+        }
+
+        /// Scalars are wrapped in a one-element sequence, so e.g. a `Vec<i32>`
+        /// or `(i32,)` can be read from an INT4 column
+        fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            let type_info = self.value.type_info();
+            let type_name = type_info.name();
+
+            if matches!(type_name, "BIT" | "VARBIT") && !self.value.is_null() {
+                let column = self.column;
+                let type_info = type_info.into_owned();
+
+                return self
+                    .decode::<PgBits>()?
+                    .into_deserializer()
+                    .deserialize_seq(visitor)
+                    .map_err(|err| err.in_column(column, type_info.name()));
+            }
+
+            // `Vec<u8>` and `[u8; N]` ask for a sequence rather than bytes
+            if type_name == "BYTEA" && !self.value.is_null() {
+                return self
+                    .decode::<PgBytea>()?
+                    .into_deserializer()
+                    .deserialize_seq(visitor);
+            }
+
+            if type_name == "tsvector" && !self.value.is_null() {
+                return self
+                    .decode::<PgTsVector>()?
+                    .into_deserializer()
+                    .deserialize_seq(visitor);
+            }
+
+            if self.is_binary_record() {
+                return PgRecordDeserializer::new(self.value.clone(), self.config)?
+                    .deserialize_seq(visitor);
+            }
+
+            let is_scalar = !(self.value.is_null()
+                || crate::is_pg_array_type(&type_info)
+                || matches!(type_name, "JSON" | "JSONB")
+                || crate::is_pg_geometry_type(type_name));
+
+            if is_scalar {
+                visitor.visit_seq(PgValueSeqAccess::new(self))
+            } else {
+                self.deserialize_any(visitor)
+            }
+        }
+
+        fn deserialize_tuple<V>(self, _len: usize, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            self.deserialize_seq(visitor)
+        }
+
+        /// Text and Postgres enum (`CREATE TYPE ... AS ENUM`) values are matched
+        /// against the variant names, so `#[serde(rename)]` and `#[serde(rename_all)]`
+        /// apply. Only unit variants can be read this way, tuple and struct variants
+        /// are not supported
+        fn deserialize_enum<V>(
+            self,
+            name: &'static str,
+            variants: &'static [&'static str],
+            visitor: V,
+        ) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            if self.value.is_null() {
+                return Err(DeserializeError::NullValue {
+                    column: self.column_name(),
+                });
+            }
+
+            let column = self.column;
+            let type_info = self.value.type_info().into_owned();
+
+            let variant = self.decode::<String>()?;
+            let deserializer: StringDeserializer<DeserializeError> = variant.into_deserializer();
+
+            deserializer
+                .deserialize_enum(name, variants, visitor)
+                .map_err(|err| err.in_column(column, type_info.name()))
+        }
+
+        deserialize_checked! {
+            deserialize_bool => "bool",
+            deserialize_i8 => "i8",
+            deserialize_i16 => "i16",
+            deserialize_i32 => "i32",
+            deserialize_i64 => "i64",
+            deserialize_u8 => "u8",
+            deserialize_u16 => "u16",
+            deserialize_u32 => "u32",
+            deserialize_u64 => "u64",
+            deserialize_f32 => "f32",
+            deserialize_f64 => "f64",
+            deserialize_char => "char",
+            deserialize_str => "str",
+            deserialize_string => "String",
+            deserialize_bytes => "bytes",
+            deserialize_byte_buf => "byte_buf",
+        }
+
+        /// Columns that serde ignores, e.g. for `#[serde(skip)]` fields, are never decoded
+        fn deserialize_ignored_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            visitor.visit_unit()
+        }
+
+        /// An INTERVAL can be read into `std::time::Duration`, which deserializes
+        /// from its `secs` and `nanos`. serde only passes on a struct's name and
+        /// fields, so any struct named `Duration` with exactly those two fields is
+        /// filled the same way when read from an INTERVAL column
+        fn deserialize_struct<V>(
+            self,
+            name: &'static str,
+            fields: &'static [&'static str],
+            visitor: V,
+        ) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            if name == "Duration" && fields == ["secs", "nanos"] && self.is_builtin("INTERVAL") {
+                let column = self.column;
+                let micros = self
+                    .interval_micros()
+                    .map_err(|err| err.in_column(column, "INTERVAL"))?;
+
+                let micros = u64::try_from(micros).map_err(|_| {
+                    DeserializeError::custom("a negative INTERVAL can't be a Duration")
+                        .in_column(column, "INTERVAL")
+                })?;
+                let duration = [
+                    ("secs", micros / 1_000_000),
+                    ("nanos", (micros % 1_000_000) * 1000),
+                ];
+
+                return MapDeserializer::new(duration.into_iter()).deserialize_any(visitor);
+            }
+
+            self.deserialize_any(visitor)
+        }
+
+        // For other types, forward to deserialize_any.
+        forward_to_deserialize_any! {
+            unit unit_struct newtype_struct
+            tuple_struct identifier map
+        }
+    }
+}
+
+mod json {
+    use crate::DeserializeError;
+    use serde::de::{self, Deserializer, Error, IntoDeserializer};
+    use serde_json::Value;
+    use sqlx::{
+        postgres::{PgTypeInfo, PgValueRef},
+        Postgres, TypeInfo, ValueRef,
+    };
+
+    /// Decodes Postgres' JSON or JSONB into serde_json::Value
+    ///
+    /// ```no_run
+    /// # fn example(row: sqlx::postgres::PgRow) -> Result<(), sqlx::Error> {
+    /// use serde_sqlx::PgJson;
+    /// use sqlx::Row;
+    ///
+    /// let doc: serde_json::Value = row.try_get::<PgJson, _>("doc")?.into_inner();
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[derive(Debug)]
+    pub struct PgJson(pub(crate) serde_json::Value);
+
+    impl<'a> sqlx::Decode<'a, sqlx::Postgres> for PgJson {
+        fn decode(value: PgValueRef<'a>) -> Result<Self, sqlx::error::BoxDynError> {
+            let is_jsonb = match value.type_info().name() {
+                "JSON" => false,
+                "JSONB" => true,
+                other => return Err(format!("{other} is not JSON or JSONB").into()),
+            };
+
+            let mut bytes = value.as_bytes()?;
+
+            // For JSONB, the first byte is a version (should be 1)
+            if is_jsonb {
+                if bytes.is_empty() || bytes[0] != 1 {
+                    return Err("invalid JSONB header".into());
+                }
+
+                // Skip the version byte
+                bytes = &bytes[1..]
+            };
+
+            let value = serde_json::from_slice(bytes)?;
+
+            Ok(PgJson(value))
+        }
+    }
+
+    impl PgJson {
+        /// Consumes the wrapper, returning the decoded JSON value
+        pub fn into_inner(self) -> serde_json::Value {
+            self.0
+        }
+    }
+
+    impl From<PgJson> for serde_json::Value {
+        fn from(json: PgJson) -> Self {
+            json.into_inner()
+        }
+    }
+
+    impl AsRef<serde_json::Value> for PgJson {
+        fn as_ref(&self) -> &serde_json::Value {
+            &self.0
+        }
+    }
+
+    impl sqlx::Type<Postgres> for PgJson {
+        fn type_info() -> PgTypeInfo {
+            PgTypeInfo::with_name("JSON")
+        }
+
+        fn compatible(ty: &PgTypeInfo) -> bool {
+            matches!(ty.name(), "JSON" | "JSONB")
+        }
+    }
+
+    pub struct PgJsonDeserializer {
+        value: Value,
+    }
+
+    /// Delegates each method to serde_json::Value's own Deserializer, so e.g. enums
+    /// and newtypes get the hint they need rather than going through `deserialize_any`
+    macro_rules! forward_to_json_value {
+        ($($method:ident($($arg:ident: $ty:ty),*)),* $(,)?) => {
+            $(
+                fn $method<V>(self, $($arg: $ty,)* visitor: V) -> Result<V::Value, Self::Error>
+                where
+                    V: de::Visitor<'de>,
+                {
+                    self.value
+                        .$method($($arg,)* visitor)
+                        .map_err(DeserializeError::custom)
+                }
+            )*
+        };
+    }
+
+    impl<'de> Deserializer<'de> for PgJsonDeserializer {
+        type Error = DeserializeError;
+
+        forward_to_json_value! {
+            deserialize_any(),
+            deserialize_bool(),
+            deserialize_i8(),
+            deserialize_i16(),
+            deserialize_i32(),
+            deserialize_i64(),
+            deserialize_i128(),
+            deserialize_u8(),
+            deserialize_u16(),
+            deserialize_u32(),
+            deserialize_u64(),
+            deserialize_u128(),
+            deserialize_f32(),
+            deserialize_f64(),
+            deserialize_char(),
+            deserialize_str(),
+            deserialize_string(),
+            deserialize_bytes(),
+            deserialize_byte_buf(),
+            deserialize_option(),
+            deserialize_unit(),
+            deserialize_unit_struct(name: &'static str),
+            deserialize_newtype_struct(name: &'static str),
+            deserialize_seq(),
+            deserialize_tuple(len: usize),
+            deserialize_tuple_struct(name: &'static str, len: usize),
+            deserialize_map(),
+            deserialize_struct(name: &'static str, fields: &'static [&'static str]),
+            deserialize_enum(name: &'static str, variants: &'static [&'static str]),
+            deserialize_identifier(),
+            deserialize_ignored_any(),
+        }
+    }
+
+    impl<'de> IntoDeserializer<'de, DeserializeError> for PgJson {
+        type Deserializer = PgJsonDeserializer;
+
+        fn into_deserializer(self) -> Self::Deserializer {
+            PgJsonDeserializer {
+                value: self.into_inner(),
+            }
+        }
+    }
+}
+
+mod range {
+    use std::ops::Bound;
+
+    use serde::de::IntoDeserializer;
+    use serde::{Deserialize, Serialize};
+    use serde_json::{Map, Value};
+    use sqlx::{
+        postgres::{types::PgRange as SqlxPgRange, PgTypeInfo, PgValueRef},
+        Postgres,
+    };
+
+    use crate::json::{PgJson, PgJsonDeserializer};
+    use crate::DeserializeError;
+
+    /// A Postgres range (INT4RANGE, DATERANGE, TSTZRANGE, etc) as read from a row.
+    ///
+    /// Unbounded sides are `None`. Discrete ranges come back canonicalized by
+    /// Postgres, e.g. `(1,3]` is read as `[2,4)`.
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    pub struct PgRange<T> {
+        pub lower: Option<T>,
+        pub upper: Option<T>,
+        pub lower_inclusive: bool,
+        pub upper_inclusive: bool,
+    }
+
+    /// Decodes a Postgres range (INT4RANGE, TSTZRANGE, etc) through sqlx's `PgRange`.
+    ///
+    /// Deserializes as a map with `lower`, `upper`, `lower_inclusive` and
+    /// `upper_inclusive` keys, where unbounded sides are `null`.
+    #[derive(Debug)]
+    pub(crate) struct PgRangeValue<T>(pub(crate) SqlxPgRange<T>);
+
+    /// A type that can appear as the bound of a Postgres range
+    pub(crate) trait PgRangeBound {
+        fn into_json(self) -> Value;
+    }
+
+    impl PgRangeBound for i32 {
+        fn into_json(self) -> Value {
+            self.into()
+        }
+    }
+
+    impl PgRangeBound for i64 {
+        fn into_json(self) -> Value {
+            self.into()
+        }
+    }
+
+    /// NUMRANGE bounds are read as `f64`, like NUMERIC values
+    impl PgRangeBound for rust_decimal::Decimal {
+        fn into_json(self) -> Value {
+            f64::try_from(self).map_or(Value::Null, Value::from)
+        }
+    }
+
+    impl PgRangeBound for chrono::NaiveDate {
+        fn into_json(self) -> Value {
+            self.to_string().into()
+        }
+    }
+
+    impl PgRangeBound for chrono::NaiveDateTime {
+        fn into_json(self) -> Value {
+            crate::array_elements::naive_timestamp_to_string(self).into()
+        }
+    }
+
+    impl PgRangeBound for chrono::DateTime<chrono::FixedOffset> {
+        fn into_json(self) -> Value {
+            self.to_rfc3339().into()
+        }
+    }
+
+    impl<T: PgRangeBound> PgRangeValue<T> {
+        fn into_json(self) -> Value {
+            fn split<T: PgRangeBound>(bound: Bound<T>) -> (Value, bool) {
+                match bound {
+                    Bound::Included(v) => (v.into_json(), true),
+                    Bound::Excluded(v) => (v.into_json(), false),
+                    Bound::Unbounded => (Value::Null, false),
+                }
+            }
+
+            let (lower, lower_inclusive) = split(self.0.start);
+            let (upper, upper_inclusive) = split(self.0.end);
+
+            let mut map = Map::new();
+            map.insert("lower".to_owned(), lower);
+            map.insert("upper".to_owned(), upper);
+            map.insert("lower_inclusive".to_owned(), lower_inclusive.into());
+            map.insert("upper_inclusive".to_owned(), upper_inclusive.into());
+
+            Value::Object(map)
+        }
+    }
+
+    impl<'a, T> sqlx::Decode<'a, Postgres> for PgRangeValue<T>
+    where
+        SqlxPgRange<T>: sqlx::Decode<'a, Postgres>,
+    {
+        fn decode(value: PgValueRef<'a>) -> Result<Self, sqlx::error::BoxDynError> {
+            SqlxPgRange::<T>::decode(value).map(PgRangeValue)
+        }
+    }
+
+    impl<T> sqlx::Type<Postgres> for PgRangeValue<T>
+    where
+        SqlxPgRange<T>: sqlx::Type<Postgres>,
+    {
+        fn type_info() -> PgTypeInfo {
+            SqlxPgRange::<T>::type_info()
+        }
+    }
+
+    impl<'de, T: PgRangeBound> IntoDeserializer<'de, DeserializeError> for PgRangeValue<T> {
+        type Deserializer = PgJsonDeserializer;
+
+        fn into_deserializer(self) -> Self::Deserializer {
+            PgJson(self.into_json()).into_deserializer()
+        }
+    }
+}
+
+#[cfg(feature = "geometry")]
+mod geometry {
+    use serde::de::IntoDeserializer;
+    use serde_json::{json, Value};
+    use sqlx::postgres::types::{PgBox, PgCircle, PgLSeg, PgLine, PgPath, PgPoint, PgPolygon};
+    use sqlx::postgres::{PgTypeInfo, PgValueRef};
+    use sqlx::Postgres;
+
+    use crate::json::{PgJson, PgJsonDeserializer};
+    use crate::DeserializeError;
+
+    /// A geometric value (LINE, BOX, PATH, etc) or an element of an array of them,
+    /// deserialized as nested sequences of coordinates
+    #[derive(Debug)]
+    pub(crate) struct PgGeometry<T>(pub(crate) T);
+
+    /// A geometric type sqlx can decode
+    pub(crate) trait PgShape {
+        fn into_json(self) -> Value;
+    }
+
+    /// `[x, y]`
+    impl PgShape for PgPoint {
+        fn into_json(self) -> Value {
+            json!([self.x, self.y])
+        }
+    }
+
+    /// `[a, b, c]`, for the line `ax + by + c = 0`
+    impl PgShape for PgLine {
+        fn into_json(self) -> Value {
+            json!([self.a, self.b, self.c])
+        }
+    }
+
+    /// `[[x1, y1], [x2, y2]]`, start then end
+    impl PgShape for PgLSeg {
+        fn into_json(self) -> Value {
+            json!([[self.start_x, self.start_y], [self.end_x, self.end_y]])
+        }
+    }
+
+    /// `[[x1, y1], [x2, y2]]`, upper right then lower left corner, as Postgres
+    /// stores them
+    impl PgShape for PgBox {
+        fn into_json(self) -> Value {
+            json!([
+                [self.upper_right_x, self.upper_right_y],
+                [self.lower_left_x, self.lower_left_y]
+            ])
+        }
+    }
+
+    /// `{"closed": bool, "points": [[x, y], ...]}`
+    impl PgShape for PgPath {
+        fn into_json(self) -> Value {
+            let points: Vec<Value> = self.points.into_iter().map(PgShape::into_json).collect();
+
+            json!({ "closed": self.closed, "points": points })
+        }
+    }
+
+    /// `[[x, y], ...]`
+    impl PgShape for PgPolygon {
+        fn into_json(self) -> Value {
+            self.points.into_iter().map(PgShape::into_json).collect()
+        }
+    }
+
+    /// `[x, y, radius]`
+    impl PgShape for PgCircle {
+        fn into_json(self) -> Value {
+            json!([self.x, self.y, self.radius])
+        }
+    }
+
+    impl<'a, T> sqlx::Decode<'a, Postgres> for PgGeometry<T>
+    where
+        T: sqlx::Decode<'a, Postgres>,
+    {
+        fn decode(value: PgValueRef<'a>) -> Result<Self, sqlx::error::BoxDynError> {
+            T::decode(value).map(PgGeometry)
+        }
+    }
+
+    impl<T> sqlx::Type<Postgres> for PgGeometry<T>
+    where
+        T: sqlx::Type<Postgres>,
+    {
+        fn type_info() -> PgTypeInfo {
+            T::type_info()
+        }
+    }
+
+    impl<'de, T: PgShape> IntoDeserializer<'de, DeserializeError> for PgGeometry<T> {
+        type Deserializer = PgJsonDeserializer;
+
+        fn into_deserializer(self) -> Self::Deserializer {
+            PgJson(self.0.into_json()).into_deserializer()
+        }
+    }
+}
+
+mod array_elements {
+    #[cfg(not(any(feature = "rust_decimal", feature = "bigdecimal")))]
+    use serde::de::value::F64Deserializer;
+    #[cfg(any(feature = "rust_decimal", feature = "bigdecimal"))]
+    use serde::de::Error as _;
+    use serde::de::{
+        value::{SeqDeserializer, StringDeserializer, U32Deserializer},
+        Deserializer, IntoDeserializer, Visitor,
+    };
+    use serde::forward_to_deserialize_any;
+    use sqlx::{
+        postgres::{
+            types::{Oid, PgInterval},
+            PgTypeInfo, PgValueFormat, PgValueRef,
+        },
+        Postgres,
+    };
+
+    use crate::DeserializeError;
+
+    /// Declares a newtype over a sqlx-decodable type that deserializes as the
+    /// string produced by `$to_string`, matching what `PgValueDeserializer`
+    /// yields for the scalar type
+    macro_rules! string_array_element {
+        ($(#[$meta:meta])* $name:ident($inner:ty), $to_string:expr) => {
+            $(#[$meta])*
+            #[derive(Debug)]
+            pub(crate) struct $name(pub(crate) $inner);
+
+            impl<'a> sqlx::Decode<'a, Postgres> for $name {
+                fn decode(value: PgValueRef<'a>) -> Result<Self, sqlx::error::BoxDynError> {
+                    <$inner as sqlx::Decode<'a, Postgres>>::decode(value).map($name)
+                }
+            }
+
+            impl sqlx::Type<Postgres> for $name {
+                fn type_info() -> PgTypeInfo {
+                    <$inner as sqlx::Type<Postgres>>::type_info()
+                }
+            }
+
+            impl<'de> IntoDeserializer<'de, DeserializeError> for $name {
+                type Deserializer = StringDeserializer<DeserializeError>;
+
+                fn into_deserializer(self) -> Self::Deserializer {
+                    let to_string: fn($inner) -> String = $to_string;
+                    to_string(self.0).into_deserializer()
+                }
+            }
+        };
+    }
+
+    string_array_element!(
+        /// A UUID array element, deserialized as its hyphenated string form
+        PgUuid(uuid::Uuid),
+        |uuid| uuid.to_string()
+    );
+
+    string_array_element!(
+        /// A DATE array element, deserialized as `YYYY-MM-DD`
+        PgDate(chrono::NaiveDate),
+        |date| date.to_string()
+    );
+
+    string_array_element!(
+        /// A TIME array element, deserialized as `HH:MM:SS[.fraction]`
+        PgTime(chrono::NaiveTime),
+        |time| time.to_string()
+    );
+
+    string_array_element!(
+        /// A TIMETZ array element, deserialized as `HH:MM:SS[.fraction]+HH:MM`
+        PgTimeTz(sqlx::postgres::types::PgTimeTz<chrono::NaiveTime, chrono::FixedOffset>),
+        |time| format!("{}{}", time.time, time.offset)
+    );
+
+    string_array_element!(
+        /// A TIMESTAMP array element, deserialized as an ISO 8601 string without an offset
+        PgTimestamp(chrono::NaiveDateTime),
+        naive_timestamp_to_string
+    );
+
+    string_array_element!(
+        /// A TIMESTAMPTZ array element, deserialized as an RFC 3339 string
+        PgTimestampTz(chrono::DateTime<chrono::Utc>),
+        |ts| ts.to_rfc3339()
+    );
+
+    /// An OID array element, read as `u32`
+    #[derive(Debug)]
+    pub(crate) struct PgOid(pub(crate) u32);
+
+    impl<'a> sqlx::Decode<'a, Postgres> for PgOid {
+        fn decode(value: PgValueRef<'a>) -> Result<Self, sqlx::error::BoxDynError> {
+            Oid::decode(value).map(|oid| PgOid(oid.0))
+        }
+    }
+
+    impl sqlx::Type<Postgres> for PgOid {
+        fn type_info() -> PgTypeInfo {
+            <Oid as sqlx::Type<Postgres>>::type_info()
+        }
+    }
+
+    impl<'de> IntoDeserializer<'de, DeserializeError> for PgOid {
+        type Deserializer = U32Deserializer<DeserializeError>;
+
+        fn into_deserializer(self) -> Self::Deserializer {
+            self.0.into_deserializer()
+        }
+    }
+
+    /// A PG_LSN value, the write-ahead log position as a byte offset. Postgres prints
+    /// it as two hex halves, e.g. `16/B374D848`
+    pub(crate) struct PgLsn(pub(crate) u64);
+
+    impl<'a> sqlx::Decode<'a, Postgres> for PgLsn {
+        fn decode(value: PgValueRef<'a>) -> Result<Self, sqlx::error::BoxDynError> {
+            match value.format() {
+                PgValueFormat::Binary => {
+                    let bytes = value.as_bytes()?.try_into()?;
+                    Ok(PgLsn(u64::from_be_bytes(bytes)))
+                }
+                PgValueFormat::Text => {
+                    let text = value.as_str()?;
+                    let (high, low) = text
+                        .split_once('/')
+                        .ok_or_else(|| format!("invalid PG_LSN {text:?}"))?;
+                    let high = u64::from_str_radix(high, 16)?;
+                    let low = u64::from_str_radix(low, 16)?;
+
+                    Ok(PgLsn((high << 32) | low))
+                }
+            }
+        }
+    }
+
+    impl sqlx::Type<Postgres> for PgLsn {
+        fn type_info() -> PgTypeInfo {
+            PgTypeInfo::with_name("pg_lsn")
+        }
+    }
+
+    /// An XID value, a 32-bit transaction ID
+    pub(crate) struct PgXid(pub(crate) u32);
+
+    impl<'a> sqlx::Decode<'a, Postgres> for PgXid {
+        fn decode(value: PgValueRef<'a>) -> Result<Self, sqlx::error::BoxDynError> {
+            match value.format() {
+                PgValueFormat::Binary => {
+                    let bytes = value.as_bytes()?.try_into()?;
+                    Ok(PgXid(u32::from_be_bytes(bytes)))
+                }
+                PgValueFormat::Text => Ok(PgXid(value.as_str()?.parse()?)),
+            }
+        }
+    }
+
+    impl sqlx::Type<Postgres> for PgXid {
+        fn type_info() -> PgTypeInfo {
+            PgTypeInfo::with_name("xid")
+        }
+    }
+
+    /// A NUMERIC array element, converted to `f64` like scalar NUMERIC values are
+    #[cfg(not(any(feature = "rust_decimal", feature = "bigdecimal")))]
+    #[derive(Debug)]
+    pub(crate) struct PgNumeric(pub(crate) f64);
+
+    #[cfg(not(any(feature = "rust_decimal", feature = "bigdecimal")))]
+    impl<'a> sqlx::Decode<'a, Postgres> for PgNumeric {
+        fn decode(value: PgValueRef<'a>) -> Result<Self, sqlx::error::BoxDynError> {
+            let numeric = rust_decimal::Decimal::decode(value)?;
+            let num = numeric
+                .try_into()
+                .map_err(|_| "Failed to parse Decimal as f64")?;
+
+            Ok(PgNumeric(num))
+        }
+    }
+
+    #[cfg(not(any(feature = "rust_decimal", feature = "bigdecimal")))]
+    impl sqlx::Type<Postgres> for PgNumeric {
+        fn type_info() -> PgTypeInfo {
+            <rust_decimal::Decimal as sqlx::Type<Postgres>>::type_info()
+        }
+    }
+
+    #[cfg(not(any(feature = "rust_decimal", feature = "bigdecimal")))]
+    impl<'de> IntoDeserializer<'de, DeserializeError> for PgNumeric {
+        type Deserializer = F64Deserializer<DeserializeError>;
+
+        fn into_deserializer(self) -> Self::Deserializer {
+            self.0.into_deserializer()
+        }
+    }
+
+    /// A NUMERIC value or array element, kept as a `rust_decimal::Decimal`.
+    ///
+    /// Deserializes as its exact string representation, which `Decimal` itself
+    /// accepts, unless an `f32` or `f64` is asked for.
+    #[cfg(all(feature = "rust_decimal", not(feature = "bigdecimal")))]
+    #[derive(Debug)]
+    pub(crate) struct PgDecimal(pub(crate) rust_decimal::Decimal);
+
+    #[cfg(all(feature = "rust_decimal", not(feature = "bigdecimal")))]
+    impl<'a> sqlx::Decode<'a, Postgres> for PgDecimal {
+        fn decode(value: PgValueRef<'a>) -> Result<Self, sqlx::error::BoxDynError> {
+            rust_decimal::Decimal::decode(value).map(PgDecimal)
+        }
+    }
+
+    #[cfg(all(feature = "rust_decimal", not(feature = "bigdecimal")))]
+    impl sqlx::Type<Postgres> for PgDecimal {
+        fn type_info() -> PgTypeInfo {
+            <rust_decimal::Decimal as sqlx::Type<Postgres>>::type_info()
+        }
+    }
+
+    #[cfg(all(feature = "rust_decimal", not(feature = "bigdecimal")))]
+    impl<'de> IntoDeserializer<'de, DeserializeError> for PgDecimal {
+        type Deserializer = Self;
+
+        fn into_deserializer(self) -> Self::Deserializer {
+            self
+        }
+    }
+
+    /// Integers are only read from whole decimals that fit the target type
+    #[cfg(all(feature = "rust_decimal", not(feature = "bigdecimal")))]
+    macro_rules! decimal_to_integer {
+        ($($method:ident => $convert:ident => $visit:ident),* $(,)?) => {
+            $(
+                fn $method<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+                where
+                    V: Visitor<'de>,
+                {
+                    use rust_decimal::prelude::ToPrimitive;
+
+                    let num = Some(self.0)
+                        .filter(|decimal| decimal.fract().is_zero())
+                        .and_then(|decimal| decimal.$convert())
+                        .ok_or_else(|| {
+                            DeserializeError::custom(format!(
+                                "{} does not fit into {}",
+                                self.0,
+                                stringify!($convert).trim_start_matches("to_")
+                            ))
+                        })?;
+
+                    visitor.$visit(num)
+                }
+            )*
+        };
+    }
+
+    #[cfg(all(feature = "rust_decimal", not(feature = "bigdecimal")))]
+    impl<'de> Deserializer<'de> for PgDecimal {
+        type Error = DeserializeError;
+
+        fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            visitor.visit_string(self.0.to_string())
+        }
+
+        fn deserialize_f32<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            self.deserialize_f64(visitor)
+        }
+
+        fn deserialize_f64<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            let num: f64 = self
+                .0
+                .try_into()
+                .map_err(|_| DeserializeError::custom("Failed to parse Decimal as f64"))?;
+
+            visitor.visit_f64(num)
+        }
+
+        decimal_to_integer! {
+            deserialize_i8 => to_i8 => visit_i8,
+            deserialize_i16 => to_i16 => visit_i16,
+            deserialize_i32 => to_i32 => visit_i32,
+            deserialize_i64 => to_i64 => visit_i64,
+            deserialize_i128 => to_i128 => visit_i128,
+            deserialize_u8 => to_u8 => visit_u8,
+            deserialize_u16 => to_u16 => visit_u16,
+            deserialize_u32 => to_u32 => visit_u32,
+            deserialize_u64 => to_u64 => visit_u64,
+            deserialize_u128 => to_u128 => visit_u128,
+        }
+
+        forward_to_deserialize_any! {
+            bool char str string
+            bytes byte_buf option unit unit_struct newtype_struct seq tuple tuple_struct
+            map struct enum identifier ignored_any
+        }
+    }
+
+    /// A NUMERIC value or array element, decoded as a `bigdecimal::BigDecimal` and
+    /// kept as its exact decimal text, so that any arbitrary-precision type can parse it.
+    ///
+    /// Deserializes as that string unless a float or an integer is asked for.
+    #[cfg(feature = "bigdecimal")]
+    #[derive(Debug)]
+    pub(crate) struct PgNumericText(pub(crate) String);
+
+    #[cfg(feature = "bigdecimal")]
+    impl<'a> sqlx::Decode<'a, Postgres> for PgNumericText {
+        fn decode(value: PgValueRef<'a>) -> Result<Self, sqlx::error::BoxDynError> {
+            // sqlx pads a binary value to whole base-10000 digits, e.g. `0.1000`, so
+            // it's put back to its display scale, the fourth word of the header
+            let scale = match value.format() {
+                PgValueFormat::Binary => value
+                    .as_bytes()?
+                    .get(6..8)
+                    .map(|word| i64::from(u16::from_be_bytes([word[0], word[1]]))),
+                PgValueFormat::Text => None,
+            };
+
+            let decimal = crate::decode_raw_pg::<bigdecimal::BigDecimal>(value)?;
+            let decimal = match scale {
+                Some(scale) => decimal.with_scale(scale),
+                None => decimal,
+            };
+
+            Ok(PgNumericText(decimal.to_plain_string()))
+        }
+    }
+
+    #[cfg(feature = "bigdecimal")]
+    impl sqlx::Type<Postgres> for PgNumericText {
+        fn type_info() -> PgTypeInfo {
+            <bigdecimal::BigDecimal as sqlx::Type<Postgres>>::type_info()
+        }
+    }
+
+    #[cfg(feature = "bigdecimal")]
+    impl<'de> IntoDeserializer<'de, DeserializeError> for PgNumericText {
+        type Deserializer = Self;
+
+        fn into_deserializer(self) -> Self::Deserializer {
+            self
+        }
+    }
+
+    /// Reads an integer from the decimal text, allowing a fraction of all zeros
+    #[cfg(feature = "bigdecimal")]
+    macro_rules! numeric_text_to_integer {
+        ($($method:ident => $int:ty => $visit:ident),* $(,)?) => {
+            $(
+                fn $method<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+                where
+                    V: Visitor<'de>,
+                {
+                    let whole = match self.0.split_once('.') {
+                        Some((whole, fraction)) if fraction.bytes().all(|b| b == b'0') => whole,
+                        Some(_) => self.0.as_str(),
+                        None => self.0.as_str(),
+                    };
+
+                    let num: $int = whole.parse().map_err(|_| {
+                        DeserializeError::custom(format!(
+                            "{} does not fit into {}",
+                            self.0,
+                            stringify!($int)
+                        ))
+                    })?;
+
+                    visitor.$visit(num)
+                }
+            )*
+        };
+    }
+
+    #[cfg(feature = "bigdecimal")]
+    impl<'de> Deserializer<'de> for PgNumericText {
+        type Error = DeserializeError;
+
+        fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            visitor.visit_string(self.0)
+        }
+
+        fn deserialize_f32<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            self.deserialize_f64(visitor)
+        }
+
+        fn deserialize_f64<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            let num: f64 = self
+                .0
+                .parse()
+                .map_err(|_| DeserializeError::custom("Failed to parse NUMERIC as f64"))?;
+
+            visitor.visit_f64(num)
+        }
+
+        numeric_text_to_integer! {
+            deserialize_i8 => i8 => visit_i8,
+            deserialize_i16 => i16 => visit_i16,
+            deserialize_i32 => i32 => visit_i32,
+            deserialize_i64 => i64 => visit_i64,
+            deserialize_i128 => i128 => visit_i128,
+            deserialize_u8 => u8 => visit_u8,
+            deserialize_u16 => u16 => visit_u16,
+            deserialize_u32 => u32 => visit_u32,
+            deserialize_u64 => u64 => visit_u64,
+            deserialize_u128 => u128 => visit_u128,
+        }
+
+        forward_to_deserialize_any! {
+            bool char str string
+            bytes byte_buf option unit unit_struct newtype_struct seq tuple tuple_struct
+            map struct enum identifier ignored_any
+        }
+    }
+
+    /// Formats a TIMESTAMP as ISO 8601 without an offset, e.g. `2024-01-31T12:30:00`,
+    /// which is what `chrono::NaiveDateTime` deserializes from
+    pub(crate) fn naive_timestamp_to_string(ts: chrono::NaiveDateTime) -> String {
+        ts.format("%Y-%m-%dT%H:%M:%S%.f").to_string()
+    }
+
+    /// How many days a month of an INTERVAL counts as, since a month has no fixed
+    /// length. 30, the same as Postgres' own `justify_days`
+    pub(crate) const INTERVAL_DAYS_PER_MONTH: i64 = 30;
+
+    /// Formats an INTERVAL as a `chrono::Duration` string, counting months as
+    /// [`INTERVAL_DAYS_PER_MONTH`] days
+    pub(crate) fn interval_to_string(interval: PgInterval) -> String {
+        let secs = interval.microseconds / 1_000_000;
+        let nanos = (interval.microseconds % 1_000_000) * 1000;
+        let days_duration = chrono::Duration::days(
+            i64::from(interval.months) * INTERVAL_DAYS_PER_MONTH + i64::from(interval.days),
+        );
+        let duration =
+            chrono::Duration::seconds(secs) + chrono::Duration::nanoseconds(nanos) + days_duration;
+
+        duration.to_string()
+    }
+
+    string_array_element!(
+        /// An INTERVAL array element, deserialized like scalar INTERVAL values
+        PgIntervalWrapper(PgInterval),
+        interval_to_string
+    );
+
+    /// A BYTEA array element
+    #[derive(Debug)]
+    pub(crate) struct PgBytea(pub(crate) Vec<u8>);
+
+    impl<'a> sqlx::Decode<'a, Postgres> for PgBytea {
+        fn decode(value: PgValueRef<'a>) -> Result<Self, sqlx::error::BoxDynError> {
+            <Vec<u8> as sqlx::Decode<'a, Postgres>>::decode(value).map(PgBytea)
+        }
+    }
+
+    impl sqlx::Type<Postgres> for PgBytea {
+        fn type_info() -> PgTypeInfo {
+            <Vec<u8> as sqlx::Type<Postgres>>::type_info()
+        }
+    }
+
+    impl<'de> IntoDeserializer<'de, DeserializeError> for PgBytea {
+        type Deserializer = PgByteaDeserializer;
+
+        fn into_deserializer(self) -> Self::Deserializer {
+            PgByteaDeserializer { bytes: self.0 }
+        }
+    }
+
+    /// Yields the bytes as a sequence of `u8`, which is what `Vec<u8>` and
+    /// `serde_json::Value` expect, or through `visit_byte_buf` when bytes are asked for
+    pub(crate) struct PgByteaDeserializer {
+        bytes: Vec<u8>,
+    }
+
+    impl<'de> Deserializer<'de> for PgByteaDeserializer {
+        type Error = DeserializeError;
+
+        fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            // Goes through `SeqDeserializer::deserialize_any` so leftover bytes,
+            // e.g. for a too-short `[u8; N]`, are an error
+            SeqDeserializer::new(self.bytes.into_iter()).deserialize_any(visitor)
+        }
+
+        fn deserialize_byte_buf<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            visitor.visit_byte_buf(self.bytes)
+        }
+
+        fn deserialize_bytes<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            self.deserialize_byte_buf(visitor)
+        }
+
+        forward_to_deserialize_any! {
+            bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+            option unit unit_struct newtype_struct seq tuple tuple_struct
+            map struct enum identifier ignored_any
+        }
+    }
+
+    /// An `ltree`, `lquery` or `ltxtquery` value's text. The binary format puts a
+    /// version byte before it
+    #[cfg(feature = "ltree")]
+    pub(crate) struct PgLTreeText(pub(crate) String);
+
+    #[cfg(feature = "ltree")]
+    impl<'a> sqlx::Decode<'a, Postgres> for PgLTreeText {
+        fn decode(value: PgValueRef<'a>) -> Result<Self, sqlx::error::BoxDynError> {
+            let text = match value.format() {
+                PgValueFormat::Text => value.as_str()?,
+                PgValueFormat::Binary => match value.as_bytes()?.split_first() {
+                    Some((1, text)) => std::str::from_utf8(text)?,
+                    Some((version, _)) => {
+                        return Err(format!("unsupported ltree version {version}").into())
+                    }
+                    None => return Err("ltree value is missing its version".into()),
+                },
+            };
+
+            Ok(PgLTreeText(text.to_owned()))
+        }
+    }
+
+    #[cfg(feature = "ltree")]
+    impl sqlx::Type<Postgres> for PgLTreeText {
+        fn type_info() -> PgTypeInfo {
+            PgTypeInfo::with_name("ltree")
+        }
+    }
+
+    /// A BIT or VARBIT value, or an element of an array of them
+    #[derive(Debug)]
+    pub(crate) struct PgBits(pub(crate) Vec<bool>);
+
+    impl<'a> sqlx::Decode<'a, Postgres> for PgBits {
+        fn decode(value: PgValueRef<'a>) -> Result<Self, sqlx::error::BoxDynError> {
+            match value.format() {
+                PgValueFormat::Text => value
+                    .as_str()?
+                    .chars()
+                    .map(|bit| match bit {
+                        '0' => Ok(false),
+                        '1' => Ok(true),
+                        other => Err(format!("invalid bit '{other}'").into()),
+                    })
+                    .collect::<Result<_, _>>()
+                    .map(PgBits),
+                // The bit length, followed by the bits packed most significant first
+                PgValueFormat::Binary => {
+                    let bytes = value.as_bytes()?;
+                    let (len, bits) = bytes
+                        .split_first_chunk::<4>()
+                        .ok_or("BIT value is missing its length")?;
+                    let len = usize::try_from(i32::from_be_bytes(*len))?;
+
+                    if bits.len() * 8 < len {
+                        return Err("BIT value is shorter than its length".into());
+                    }
+
+                    Ok(PgBits(
+                        (0..len)
+                            .map(|idx| bits[idx / 8] & (0x80 >> (idx % 8)) != 0)
+                            .collect(),
+                    ))
+                }
+            }
+        }
+    }
+
+    impl sqlx::Type<Postgres> for PgBits {
+        fn type_info() -> PgTypeInfo {
+            PgTypeInfo::with_name("VARBIT")
+        }
+    }
+
+    impl<'de> IntoDeserializer<'de, DeserializeError> for PgBits {
+        type Deserializer = PgBitsDeserializer;
+
+        fn into_deserializer(self) -> Self::Deserializer {
+            PgBitsDeserializer { bits: self.0 }
+        }
+    }
+
+    /// Yields the bits as a string of `0`s and `1`s, or as a sequence of `bool`
+    /// when one is asked for
+    pub(crate) struct PgBitsDeserializer {
+        bits: Vec<bool>,
+    }
+
+    impl<'de> Deserializer<'de> for PgBitsDeserializer {
+        type Error = DeserializeError;
+
+        fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            let bits = self
+                .bits
+                .iter()
+                .map(|&bit| if bit { '1' } else { '0' })
+                .collect();
+
+            visitor.visit_string(bits)
+        }
+
+        fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            visitor.visit_seq(SeqDeserializer::new(self.bits.into_iter()))
+        }
+
+        forward_to_deserialize_any! {
+            bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+            bytes byte_buf option unit unit_struct newtype_struct tuple tuple_struct
+            map struct enum identifier ignored_any
+        }
+    }
+}
+
+mod debug {
+    use std::fmt;
+
+    use serde::Deserialize;
+    use sqlx::{postgres::PgRow, Column, Row, TypeInfo, ValueRef};
+
+    use crate::deserializers::PgValueDeserializer;
+    use crate::DeserializerConfig;
+
+    /// Wraps a [`PgRow`] so it can be printed, e.g. when logging.
+    ///
+    /// Formats as `{col: value, col2: value2, ...}`, NULLs showing up as `NULL`.
+    pub struct PgRowDebug<'a>(pub &'a PgRow);
+
+    /// Returns a wrapper that formats `row` for debugging purposes
+    pub fn debug_row(row: &PgRow) -> PgRowDebug<'_> {
+        PgRowDebug(row)
+    }
+
+    impl PgRowDebug<'_> {
+        fn columns(&self) -> impl Iterator<Item = (&str, String)> {
+            self.0.columns().iter().map(|column| {
+                let value = match self.0.try_get_raw(column.ordinal()) {
+                    Ok(value) => stringify(PgValueDeserializer {
+                        value,
+                        column: Some(column.name()),
+                        config: DeserializerConfig::default_ref(),
+                    }),
+                    Err(err) => format!("<{err}>"),
+                };
+
+                (column.name(), value)
+            })
+        }
+    }
+
+    fn stringify(deserializer: PgValueDeserializer<'_>) -> String {
+        let type_name = deserializer.value.type_info().name().to_owned();
+
+        match serde_json::Value::deserialize(deserializer) {
+            Ok(serde_json::Value::Null) => "NULL".to_owned(),
+            Ok(serde_json::Value::String(s)) => s,
+            Ok(other) => other.to_string(),
+            Err(_) => format!("<{type_name}>"),
+        }
+    }
+
+    impl fmt::Display for PgRowDebug<'_> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("{")?;
+            for (idx, (name, value)) in self.columns().enumerate() {
+                if idx > 0 {
+                    f.write_str(", ")?;
+                }
+                write!(f, "{name}: {value}")?;
+            }
+            f.write_str("}")
+        }
+    }
+
+    impl fmt::Debug for PgRowDebug<'_> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_map().entries(self.columns()).finish()
+        }
+    }
+}
+
+mod record {
+    use serde::de::{self, DeserializeSeed, Error as _, MapAccess, SeqAccess, Visitor};
+    use serde::forward_to_deserialize_any;
+    use sqlx::postgres::{PgTypeInfo, PgTypeKind, PgValue, PgValueRef};
+    use sqlx::{Postgres, Value, ValueRef};
+
+    use crate::deserializers::PgValueDeserializer;
+    use crate::{DeserializeError, DeserializerConfig};
+
+    /// A record field, copied out as-is so it can go through `PgValueDeserializer`
+    /// like any column
+    struct PgRecordField(PgValue);
+
+    impl<'a> sqlx::Decode<'a, Postgres> for PgRecordField {
+        fn decode(value: PgValueRef<'a>) -> Result<Self, sqlx::error::BoxDynError> {
+            Ok(PgRecordField(ValueRef::to_owned(&value)))
+        }
+    }
+
+    impl sqlx::Type<Postgres> for PgRecordField {
+        fn type_info() -> PgTypeInfo {
+            PgTypeInfo::with_name("RECORD")
+        }
+
+        fn compatible(_ty: &PgTypeInfo) -> bool {
+            true
+        }
+    }
+
+    /// The fields of an anonymous `RECORD`, read as a sequence, or of a composite
+    /// type, read as a map keyed by the field names (or as a sequence when asked)
+    pub(crate) struct PgRecordDeserializer<'a> {
+        fields: std::vec::IntoIter<(Option<String>, PgValue)>,
+        config: &'a DeserializerConfig,
+        named: bool,
+    }
+
+    impl<'a> PgRecordDeserializer<'a> {
+        /// `value` must be a non-NULL record in the binary format
+        pub(crate) fn new(
+            value: PgValueRef<'_>,
+            config: &'a DeserializerConfig,
+        ) -> Result<Self, DeserializeError> {
+            let type_info = value.type_info().into_owned();
+            let names = match type_info.kind() {
+                PgTypeKind::Composite(fields) => Some(
+                    fields
+                        .iter()
+                        .map(|(name, _)| name.clone())
+                        .collect::<Vec<_>>(),
+                ),
+                _ => None,
+            };
+
+            // The binary format starts with the field count
+            let count = value
+                .as_bytes()
+                .ok()
+                .and_then(|bytes| bytes.first_chunk::<4>())
+                .map(|count| i32::from_be_bytes(*count))
+                .ok_or_else(|| {
+                    DeserializeError::custom("record value is missing its field count")
+                })?;
+
+            let mut decoder = sqlx::postgres::types::PgRecordDecoder::new(value)
+                .map_err(DeserializeError::custom)?;
+            let fields = (0..usize::try_from(count).unwrap_or(0))
+                .map(|idx| {
+                    let field = decoder
+                        .try_decode::<PgRecordField>()
+                        .map_err(DeserializeError::custom)?;
+                    let name = names.as_ref().and_then(|names| names.get(idx).cloned());
+
+                    Ok((name, field.0))
+                })
+                .collect::<Result<Vec<_>, DeserializeError>>()?;
+
+            Ok(PgRecordDeserializer {
+                fields: fields.into_iter(),
+                config,
+                named: names.is_some(),
+            })
+        }
+
+        fn field_deserializer<'v>(
+            &self,
+            name: Option<&'v str>,
+            value: &'v PgValue,
+        ) -> PgValueDeserializer<'v>
+        where
+            'a: 'v,
+        {
+            PgValueDeserializer {
+                value: value.as_ref(),
+                column: name,
+                config: self.config,
+            }
+        }
+    }
+
+    impl<'de> de::Deserializer<'de> for PgRecordDeserializer<'_> {
+        type Error = DeserializeError;
+
+        fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            if self.named {
+                visitor.visit_map(PgRecordMapAccess {
+                    record: self,
+                    value: None,
+                })
+            } else {
+                visitor.visit_seq(self)
+            }
+        }
+
+        fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            visitor.visit_seq(self)
+        }
+
+        fn deserialize_tuple<V>(self, _len: usize, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            visitor.visit_seq(self)
+        }
+
+        fn deserialize_tuple_struct<V>(
+            self,
+            _name: &'static str,
+            _len: usize,
+            visitor: V,
+        ) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            visitor.visit_seq(self)
+        }
+
+        fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            visitor.visit_some(self)
+        }
+
+        forward_to_deserialize_any! {
+            bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+            bytes byte_buf unit unit_struct newtype_struct map struct enum
+            identifier ignored_any
+        }
+    }
+
+    impl<'de> SeqAccess<'de> for PgRecordDeserializer<'_> {
+        type Error = DeserializeError;
+
+        fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
+        where
+            T: DeserializeSeed<'de>,
+        {
+            let Some((name, value)) = self.fields.next() else {
+                return Ok(None);
+            };
+
+            seed.deserialize(self.field_deserializer(name.as_deref(), &value))
+                .map(Some)
+        }
+
+        fn size_hint(&self) -> Option<usize> {
+            Some(self.fields.len())
+        }
+    }
+
+    /// A composite value's fields by name
+    struct PgRecordMapAccess<'a> {
+        record: PgRecordDeserializer<'a>,
+        value: Option<(String, PgValue)>,
+    }
+
+    impl<'de> MapAccess<'de> for PgRecordMapAccess<'_> {
+        type Error = DeserializeError;
+
+        fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+        where
+            K: DeserializeSeed<'de>,
+        {
+            let Some((name, value)) = self.record.fields.next() else {
+                return Ok(None);
+            };
+            let name = name.unwrap_or_default();
+            let key =
+                seed.deserialize(de::value::StrDeserializer::<DeserializeError>::new(&name))?;
+            self.value = Some((name, value));
+
+            Ok(Some(key))
+        }
+
+        fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+        where
+            V: DeserializeSeed<'de>,
+        {
+            let (name, value) = self
+                .value
+                .take()
+                .ok_or_else(|| DeserializeError::custom("record value requested before its key"))?;
+
+            seed.deserialize(self.record.field_deserializer(Some(&name), &value))
+        }
+
+        fn size_hint(&self) -> Option<usize> {
+            Some(self.record.fields.len())
+        }
+    }
+}
+
+mod inspect {
+    use sqlx::postgres::{PgRow, PgValueRef};
+    use sqlx::{Column, Row, TypeInfo, ValueRef};
+
+    /// Looks at a row's columns and raw values directly, without going through serde
+    #[derive(Clone, Copy)]
+    pub struct PgRowInspector<'a>(pub &'a PgRow);
+
+    impl<'a> PgRowInspector<'a> {
+        /// Each column's name and Postgres type name, in order
+        pub fn columns(&self) -> impl Iterator<Item = (&'a str, &'a str)> {
+            self.0
+                .columns()
+                .iter()
+                .map(|column| (column.name(), column.type_info().name()))
+        }
+
+        pub fn column_count(&self) -> usize {
+            self.0.columns().len()
+        }
+
+        /// Whether the column is NULL. A missing column is not
+        pub fn is_null(&self, name: &str) -> bool {
+            self.raw_value(name).is_some_and(|value| value.is_null())
+        }
+
+        /// The column's undecoded value, or `None` if there's no such column
+        pub fn raw_value(&self, name: &str) -> Option<PgValueRef<'a>> {
+            self.0.try_get_raw(name).ok()
+        }
+    }
+}
+
+mod iter {
+    use std::fmt;
+
+    use sqlx::postgres::PgRow;
+    use sqlx::{Column, Row};
+
+    use crate::{DeserializerConfig, PgValueDeserializer};
+
+    /// Iterates over a row's columns, yielding each column's name with a
+    /// [`PgValueDeserializer`] for its value
+    ///
+    /// ```no_run
+    /// # fn example(row: sqlx::postgres::PgRow) -> Result<(), serde_sqlx::DeserializeError> {
+    /// use serde::Deserialize;
+    /// use serde_sqlx::PgRowColumns;
+    ///
+    /// for (name, value) in PgRowColumns::new(&row) {
+    ///     let value = serde_json::Value::deserialize(value)?;
+    ///     println!("{name}: {value}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[derive(Clone)]
+    pub struct PgRowColumns<'a> {
+        row: &'a PgRow,
+        index: usize,
+    }
+
+    impl<'a> PgRowColumns<'a> {
+        pub fn new(row: &'a PgRow) -> Self {
+            PgRowColumns { row, index: 0 }
+        }
+    }
+
+    impl<'a> Iterator for PgRowColumns<'a> {
+        type Item = (&'a str, PgValueDeserializer<'a>);
+
+        fn next(&mut self) -> Option<Self::Item> {
+            let column = self.row.columns().get(self.index)?;
+            let value = self.row.try_get_raw(self.index).ok()?;
+            self.index += 1;
+
+            let deserializer = PgValueDeserializer {
+                value,
+                column: Some(column.name()),
+                config: DeserializerConfig::default_ref(),
+            };
+            Some((column.name(), deserializer))
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            let remaining = self.row.columns().len().saturating_sub(self.index);
+            (remaining, Some(remaining))
+        }
+    }
+
+    impl ExactSizeIterator for PgRowColumns<'_> {}
+
+    /// Shows where in the row iteration is, not the row's values
+    impl fmt::Debug for PgRowColumns<'_> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("PgRowColumns")
+                .field("columns", &self.row.columns().len())
+                .field("index", &self.index)
+                .finish_non_exhaustive()
+        }
+    }
+}
+
+mod from_row {
+    use sqlx::postgres::PgRow;
+
+    use crate::DeserializeError;
+
+    /// A type read from a row one column at a time, usually through
+    /// `#[derive(FromPgRow)]` with the `derive` feature. Unlike [`crate::from_pg_row`],
+    /// the type itself doesn't need to implement `Deserialize`, only its fields do
+    pub trait FromPgRow: Sized {
+        fn from_pg_row(row: &PgRow) -> Result<Self, DeserializeError>;
+    }
+}
+
+/// Used by the code `#[derive(FromPgRow)]` generates. Not public API
+#[doc(hidden)]
+pub mod __private {
+    pub use serde::de::Deserialize;
+    pub use sqlx::postgres::PgRow;
+
+    use crate::DeserializeError;
+
+    /// Deserializes the named column on its own
+    pub fn column<T>(row: &PgRow, name: &str) -> Result<T, DeserializeError>
+    where
+        T: for<'de> Deserialize<'de>,
+    {
+        crate::from_pg_row_column_by_name(row, name)
+    }
+}
+
+mod serialize {
+    use serde::ser::{Error, Serialize, SerializeMap, Serializer};
+    use sqlx::postgres::PgRow;
+    use sqlx::{Column, Row};
+
+    /// Serializes a row as a map of column name to value, e.g.
+    /// `serde_json::to_string(&PgRowSerializer::new(&row))`.
+    ///
+    /// Each value is decoded as it's serialized, the same way [`crate::row_to_json`]
+    /// reads it
+    #[derive(Clone, Copy)]
+    pub struct PgRowSerializer<'a> {
+        row: &'a PgRow,
+    }
+
+    impl<'a> PgRowSerializer<'a> {
+        pub fn new(row: &'a PgRow) -> Self {
+            Self { row }
+        }
+    }
+
+    impl Serialize for PgRowSerializer<'_> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let columns = self.row.columns();
+            let mut map = serializer.serialize_map(Some(columns.len()))?;
+
+            for column in columns {
+                let value = crate::column_to_json(self.row, column).map_err(S::Error::custom)?;
+                map.serialize_entry(column.name(), &value)?;
+            }
+
+            map.end()
+        }
+    }
+}
+
+mod hstore {
+    use std::collections::btree_map;
+
+    use serde::de::{self, value::StringDeserializer, Error as _, IntoDeserializer, MapAccess};
+    use serde::forward_to_deserialize_any;
+    use sqlx::postgres::types::PgHstore;
+
+    use crate::DeserializeError;
+
+    /// Deserializes a decoded `hstore` as a map of its keys to nullable text values
+    pub(crate) struct PgHstoreDeserializer {
+        entries: btree_map::IntoIter<String, Option<String>>,
+        value: Option<Option<String>>,
+    }
+
+    impl PgHstoreDeserializer {
+        pub(crate) fn new(hstore: PgHstore) -> Self {
+            Self {
+                entries: hstore.0.into_iter(),
+                value: None,
+            }
+        }
+    }
+
+    impl<'de> de::Deserializer<'de> for PgHstoreDeserializer {
+        type Error = DeserializeError;
+
+        fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: de::Visitor<'de>,
+        {
+            visitor.visit_map(self)
+        }
+
+        forward_to_deserialize_any! {
+            bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+            bytes byte_buf option unit unit_struct newtype_struct seq tuple
+            tuple_struct map struct enum identifier ignored_any
+        }
+    }
+
+    impl<'de> MapAccess<'de> for PgHstoreDeserializer {
+        type Error = DeserializeError;
+
+        fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+        where
+            K: de::DeserializeSeed<'de>,
+        {
+            let Some((key, value)) = self.entries.next() else {
+                return Ok(None);
+            };
+            self.value = Some(value);
+
+            let key: StringDeserializer<DeserializeError> = key.into_deserializer();
+            seed.deserialize(key).map(Some)
+        }
+
+        fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+        where
+            V: de::DeserializeSeed<'de>,
+        {
+            let value = self
+                .value
+                .take()
+                .ok_or_else(|| DeserializeError::custom("hstore value requested before its key"))?;
 
-    impl<'de> IntoDeserializer<'de> for PgJson {
-        type Deserializer = PgJsonDeserializer;
+            seed.deserialize(HstoreValueDeserializer(value))
+        }
+
+        fn size_hint(&self) -> Option<usize> {
+            Some(self.entries.len())
+        }
+    }
+
+    /// A single hstore value, which may be NULL
+    struct HstoreValueDeserializer(Option<String>);
+
+    impl<'de> de::Deserializer<'de> for HstoreValueDeserializer {
+        type Error = DeserializeError;
+
+        fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: de::Visitor<'de>,
+        {
+            match self.0 {
+                Some(value) => visitor.visit_string(value),
+                None => visitor.visit_none(),
+            }
+        }
+
+        fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: de::Visitor<'de>,
+        {
+            match self.0 {
+                Some(value) => visitor.visit_some(value.into_deserializer()),
+                None => visitor.visit_none(),
+            }
+        }
+
+        forward_to_deserialize_any! {
+            bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+            bytes byte_buf unit unit_struct newtype_struct seq tuple
+            tuple_struct map struct enum identifier ignored_any
+        }
+    }
+}
+
+mod tsvector {
+    use std::fmt::{self, Write as _};
+
+    use serde::de::{value::SeqDeserializer, Deserializer, IntoDeserializer, Visitor};
+    use serde::forward_to_deserialize_any;
+    use sqlx::postgres::{PgTypeInfo, PgValueFormat, PgValueRef};
+    use sqlx::Postgres;
+
+    use crate::DeserializeError;
+
+    /// A full-text search document: its lexemes, each with its positions. A
+    /// position's top two bits hold its weight, from D (0) to A (3)
+    pub(crate) struct PgTsVector(Vec<(String, Vec<u16>)>);
+
+    impl<'a> sqlx::Decode<'a, Postgres> for PgTsVector {
+        fn decode(value: PgValueRef<'a>) -> Result<Self, sqlx::error::BoxDynError> {
+            match value.format() {
+                PgValueFormat::Text => parse_text(value.as_str()?),
+                PgValueFormat::Binary => parse_binary(value.as_bytes()?),
+            }
+        }
+    }
+
+    impl sqlx::Type<Postgres> for PgTsVector {
+        fn type_info() -> PgTypeInfo {
+            PgTypeInfo::with_name("tsvector")
+        }
+    }
+
+    /// The lexeme count, then each lexeme as a NUL-terminated string followed by
+    /// its position count and positions
+    fn parse_binary(mut bytes: &[u8]) -> Result<PgTsVector, sqlx::error::BoxDynError> {
+        fn take<const N: usize>(bytes: &mut &[u8]) -> Result<[u8; N], &'static str> {
+            let (taken, rest) = bytes
+                .split_first_chunk::<N>()
+                .ok_or("tsvector value is truncated")?;
+            *bytes = rest;
+            Ok(*taken)
+        }
+
+        let count = i32::from_be_bytes(take(&mut bytes)?);
+        let mut lexemes = Vec::with_capacity(usize::try_from(count)?);
+
+        for _ in 0..count {
+            let end = bytes
+                .iter()
+                .position(|&byte| byte == 0)
+                .ok_or("tsvector lexeme is missing its terminator")?;
+            let word = std::str::from_utf8(&bytes[..end])?.to_owned();
+            bytes = &bytes[end + 1..];
+
+            let positions = (0..u16::from_be_bytes(take(&mut bytes)?))
+                .map(|_| take(&mut bytes).map(u16::from_be_bytes))
+                .collect::<Result<_, _>>()?;
+
+            lexemes.push((word, positions));
+        }
+
+        Ok(PgTsVector(lexemes))
+    }
+
+    /// Postgres' output format, e.g. `'cat':2 'it''s':1A,3`
+    fn parse_text(text: &str) -> Result<PgTsVector, sqlx::error::BoxDynError> {
+        let mut chars = text.chars().peekable();
+        let mut lexemes = Vec::new();
+
+        loop {
+            while chars.next_if_eq(&' ').is_some() {}
+            let Some(first) = chars.next() else {
+                break;
+            };
+
+            let mut word = String::new();
+            if first == '\'' {
+                loop {
+                    match chars.next().ok_or("unterminated tsvector lexeme")? {
+                        '\'' if chars.next_if_eq(&'\'').is_none() => break,
+                        '\\' => word.push(chars.next().ok_or("unterminated tsvector lexeme")?),
+                        c => word.push(c),
+                    }
+                }
+            } else {
+                word.push(first);
+                while let Some(c) = chars.next_if(|&c| c != ' ' && c != ':') {
+                    word.push(c);
+                }
+            }
+
+            let mut positions = Vec::new();
+            if chars.next_if_eq(&':').is_some() {
+                loop {
+                    let mut digits = String::new();
+                    while let Some(digit) = chars.next_if(char::is_ascii_digit) {
+                        digits.push(digit);
+                    }
+                    let weight = match chars.next_if(|c| matches!(c, 'A'..='D')) {
+                        Some(weight) => 3 - (weight as u16 - 'A' as u16),
+                        None => 0,
+                    };
+                    positions.push(digits.parse::<u16>()? | weight << 14);
+
+                    if chars.next_if_eq(&',').is_none() {
+                        break;
+                    }
+                }
+            }
+
+            lexemes.push((word, positions));
+        }
+
+        Ok(PgTsVector(lexemes))
+    }
+
+    impl fmt::Display for PgTsVector {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            for (idx, (word, positions)) in self.0.iter().enumerate() {
+                if idx > 0 {
+                    f.write_char(' ')?;
+                }
+
+                f.write_char('\'')?;
+                for c in word.chars() {
+                    if matches!(c, '\'' | '\\') {
+                        f.write_char(c)?;
+                    }
+                    f.write_char(c)?;
+                }
+                f.write_char('\'')?;
+
+                for (idx, position) in positions.iter().enumerate() {
+                    f.write_char(if idx == 0 { ':' } else { ',' })?;
+                    write!(f, "{}", position & 0x3fff)?;
+                    match position >> 14 {
+                        3 => f.write_char('A')?,
+                        2 => f.write_char('B')?,
+                        1 => f.write_char('C')?,
+                        _ => {}
+                    }
+                }
+            }
+
+            Ok(())
+        }
+    }
+
+    impl<'de> IntoDeserializer<'de, DeserializeError> for PgTsVector {
+        type Deserializer = PgTsVectorDeserializer;
 
         fn into_deserializer(self) -> Self::Deserializer {
-            PgJsonDeserializer { value: self.0 }
+            PgTsVectorDeserializer(self)
+        }
+    }
+
+    /// Yields the tsvector's text form, or its lexemes when a sequence is asked for
+    pub(crate) struct PgTsVectorDeserializer(PgTsVector);
+
+    impl<'de> Deserializer<'de> for PgTsVectorDeserializer {
+        type Error = DeserializeError;
+
+        fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            visitor.visit_string(self.0.to_string())
+        }
+
+        fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            let words = self.0 .0.into_iter().map(|(word, _)| word);
+
+            visitor.visit_seq(SeqDeserializer::new(words))
+        }
+
+        forward_to_deserialize_any! {
+            bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+            bytes byte_buf option unit unit_struct newtype_struct tuple tuple_struct
+            map struct enum identifier ignored_any
+        }
+    }
+}
+
+/// Deserializing rows as they arrive, without collecting them first
+#[cfg(feature = "stream")]
+pub mod stream {
+    use futures_core::Stream;
+    use futures_util::StreamExt;
+    use serde::de::{Deserialize, Error};
+    use sqlx::postgres::PgRow;
+
+    use crate::DeserializeError;
+
+    /// Deserializes each row of `stream`, e.g. from `sqlx::query(..).fetch(&pool)`.
+    /// Errors from the stream itself are passed on as [`DeserializeError::Custom`]
+    pub fn deserialize_stream<T, S>(stream: S) -> impl Stream<Item = Result<T, DeserializeError>>
+    where
+        S: Stream<Item = Result<PgRow, sqlx::Error>>,
+        T: for<'de> Deserialize<'de>,
+    {
+        stream.map(|row| {
+            row.map_err(DeserializeError::custom)
+                .and_then(|row| crate::from_pg_row_ref(&row))
+        })
+    }
+}
+
+/// Using serde-sqlx through sqlx's own [`FromRow`](sqlx::FromRow)
+///
+/// Unlike `#[derive(sqlx::FromRow)]`, the target only needs `Deserialize`, and gets
+/// serde's attributes, flattening and JSON columns. The cost is going through serde
+/// for every column rather than decoding each field with its `sqlx::Decode` impl
+#[cfg(feature = "sqlx-compat")]
+pub mod sqlx_compat {
+    use serde::de::Deserialize;
+    use sqlx::postgres::PgRow;
+
+    /// Deserializes rows into `T` wherever sqlx asks for a [`FromRow`](sqlx::FromRow)
+    /// type, e.g. `sqlx::query_as::<_, SerdeFromRow<User>>(..)`
+    #[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+    pub struct SerdeFromRow<T>(pub T);
+
+    impl<T> SerdeFromRow<T> {
+        pub fn into_inner(self) -> T {
+            self.0
+        }
+    }
+
+    impl<'r, T> sqlx::FromRow<'r, PgRow> for SerdeFromRow<T>
+    where
+        T: for<'de> Deserialize<'de>,
+    {
+        fn from_row(row: &'r PgRow) -> Result<Self, sqlx::Error> {
+            crate::from_pg_row_ref(row)
+                .map(SerdeFromRow)
+                .map_err(|err| sqlx::Error::Decode(Box::new(err)))
+        }
+    }
+}
+
+/// Helpers for INET columns, for use with `#[serde(deserialize_with = "...")]`
+pub mod inet {
+    use std::{net::IpAddr, str::FromStr};
+
+    use serde::{de::Error, Deserialize, Deserializer};
+
+    /// Deserializes an INET column into a [`std::net::IpAddr`]
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<IpAddr, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let addr = String::deserialize(deserializer)?;
+
+        IpAddr::from_str(&addr).map_err(D::Error::custom)
+    }
+}
+
+mod error {
+    use std::fmt;
+
+    /// The error returned when a row or value can't be deserialized
+    #[derive(Debug)]
+    pub enum DeserializeError {
+        /// The column's Postgres type can't be deserialized into the requested Rust type
+        TypeMismatch {
+            column: String,
+            pg_type: String,
+            target_type: &'static str,
+        },
+        /// The column is NULL, but the Rust type isn't an `Option`
+        NullValue { column: String },
+        /// sqlx could not decode the column's value
+        DecodeFailure {
+            column: String,
+            pg_type: String,
+            source: Box<dyn std::error::Error + Send + Sync>,
+        },
+        /// Any other error, including those raised by `Deserialize` implementations
+        Custom(String),
+    }
+
+    impl fmt::Display for DeserializeError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                DeserializeError::TypeMismatch {
+                    column,
+                    pg_type,
+                    target_type,
+                } => write!(
+                    f,
+                    "failed to deserialize column '{column}' (PG type {pg_type}): cannot be deserialized into {target_type}"
+                ),
+                DeserializeError::NullValue { column } => {
+                    write!(
+                        f,
+                        "failed to deserialize column '{column}': NULL but a value was expected"
+                    )
+                }
+                DeserializeError::DecodeFailure {
+                    column,
+                    pg_type,
+                    source,
+                } => {
+                    write!(
+                        f,
+                        "failed to deserialize column '{column}' (PG type {pg_type}): {source}"
+                    )
+                }
+                DeserializeError::Custom(message) => f.write_str(message),
+            }
+        }
+    }
+
+    impl DeserializeError {
+        /// Prefixes a [`DeserializeError::Custom`] message with the column and PG type
+        /// it came from. The other variants carry the column already
+        pub(crate) fn in_column(self, column: Option<&str>, pg_type: &str) -> Self {
+            match (self, column) {
+                (DeserializeError::Custom(message), Some(column)) => {
+                    DeserializeError::Custom(format!(
+                        "failed to deserialize column '{column}' (PG type {pg_type}): {message}"
+                    ))
+                }
+                (DeserializeError::Custom(message), None) => DeserializeError::Custom(format!(
+                    "failed to deserialize value (PG type {pg_type}): {message}"
+                )),
+                (err, _) => err,
+            }
+        }
+    }
+
+    impl std::error::Error for DeserializeError {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            match self {
+                DeserializeError::DecodeFailure { source, .. } => Some(source.as_ref()),
+                _ => None,
+            }
+        }
+    }
+
+    impl serde::de::Error for DeserializeError {
+        fn custom<T: fmt::Display>(msg: T) -> Self {
+            DeserializeError::Custom(msg.to_string())
+        }
+    }
+
+    impl From<serde::de::value::Error> for DeserializeError {
+        fn from(err: serde::de::value::Error) -> Self {
+            DeserializeError::Custom(err.to_string())
+        }
+    }
+}
+
+mod config {
+    use std::sync::OnceLock;
+
+    use crate::dispatch::PgTypeDispatch;
+
+    /// Options controlling how rows are deserialized.
+    ///
+    /// Used through [`from_pg_row_with_config`](crate::from_pg_row_with_config).
+    #[derive(Debug, Default)]
+    pub struct DeserializerConfig {
+        /// Custom handlers for Postgres types, keyed by type name
+        pub type_dispatch: PgTypeDispatch,
+        /// Names of TEXT/VARCHAR columns whose contents should be parsed as JSON
+        pub text_columns_as_json: Vec<String>,
+        /// Reject coercions that aren't [`Compatibility::Safe`](crate::pg_type_compatibility::Compatibility::Safe),
+        /// e.g. FLOAT8 into `f32`
+        pub require_safe_coercions: bool,
+        /// Match column names to struct fields ignoring ASCII case, e.g. a
+        /// `"UserID"` column fills a `userid` field
+        pub case_insensitive_columns: bool,
+        /// Convert `snake_case` column names to `camelCase` before matching them to
+        /// fields, for structs with `#[serde(rename_all = "camelCase")]`
+        pub snake_to_camel_columns: bool,
+        /// Leave out columns that don't match any of the struct's fields without
+        /// decoding them, even with `#[serde(deny_unknown_fields)]`
+        pub skip_unknown_columns: bool,
+        /// Read MONEY values as `f64` dollars rather than `i64` cents. Assumes
+        /// the database's `lc_monetary` uses two decimal places
+        pub money_as_dollars: bool,
+        /// How TIMESTAMPTZ values are read
+        pub timestamp_format: TimestampFormat,
+    }
+
+    /// The representation TIMESTAMPTZ values are deserialized as
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    pub enum TimestampFormat {
+        /// An RFC 3339 string in UTC, e.g. `2024-01-01T06:30:00+00:00`
+        #[default]
+        Iso8601,
+        /// Seconds since the Unix epoch, as an `i64`
+        UnixSeconds,
+        /// Milliseconds since the Unix epoch, as an `i64`
+        UnixMillis,
+    }
+
+    impl DeserializerConfig {
+        /// The configuration used by [`from_pg_row`](crate::from_pg_row)
+        pub(crate) fn default_ref() -> &'static DeserializerConfig {
+            static DEFAULT: OnceLock<DeserializerConfig> = OnceLock::new();
+
+            DEFAULT.get_or_init(DeserializerConfig::default)
+        }
+    }
+}
+
+mod dispatch {
+    use std::{collections::HashMap, fmt, marker::PhantomData};
+
+    use crate::DeserializeError;
+    use serde::de::{Error as _, Visitor};
+    use sqlx::postgres::PgValueRef;
+
+    /// A handler for a Postgres type: decodes the raw value and feeds it to the visitor
+    pub type PgTypeHandler = Box<
+        dyn Fn(PgValueRef<'_>, &mut dyn ErasedVisitor) -> Result<(), DeserializeError>
+            + Send
+            + Sync,
+    >;
+
+    /// Maps Postgres type names (as in `TypeInfo::name`, e.g. `"MONEY"`) to handlers.
+    ///
+    /// Handlers registered here are consulted before the built-in type handling,
+    /// so they can both add support for new types (e.g. from extensions) and
+    /// override how built-in ones are deserialized. NULLs never reach a handler.
+    #[derive(Default)]
+    pub struct PgTypeDispatch {
+        handlers: HashMap<&'static str, PgTypeHandler>,
+    }
+
+    impl PgTypeDispatch {
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Registers a handler for `type_name`, replacing any previous one
+        pub fn insert<F>(&mut self, type_name: &'static str, handler: F)
+        where
+            F: Fn(PgValueRef<'_>, &mut dyn ErasedVisitor) -> Result<(), DeserializeError>
+                + Send
+                + Sync
+                + 'static,
+        {
+            self.handlers.insert(type_name, Box::new(handler));
+        }
+
+        pub fn get(&self, type_name: &str) -> Option<&PgTypeHandler> {
+            self.handlers.get(type_name)
+        }
+    }
+
+    impl fmt::Debug for PgTypeDispatch {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_set().entries(self.handlers.keys()).finish()
+        }
+    }
+
+    /// An object-safe subset of [`serde::de::Visitor`], handed to [`PgTypeHandler`]s.
+    ///
+    /// Only one `visit_*` method may be called per value.
+    pub trait ErasedVisitor {
+        fn visit_bool(&mut self, v: bool) -> Result<(), DeserializeError>;
+        fn visit_i16(&mut self, v: i16) -> Result<(), DeserializeError>;
+        fn visit_i32(&mut self, v: i32) -> Result<(), DeserializeError>;
+        fn visit_i64(&mut self, v: i64) -> Result<(), DeserializeError>;
+        fn visit_u32(&mut self, v: u32) -> Result<(), DeserializeError>;
+        fn visit_u64(&mut self, v: u64) -> Result<(), DeserializeError>;
+        fn visit_f32(&mut self, v: f32) -> Result<(), DeserializeError>;
+        fn visit_f64(&mut self, v: f64) -> Result<(), DeserializeError>;
+        fn visit_str(&mut self, v: &str) -> Result<(), DeserializeError>;
+        fn visit_string(&mut self, v: String) -> Result<(), DeserializeError>;
+        fn visit_bytes(&mut self, v: &[u8]) -> Result<(), DeserializeError>;
+        fn visit_none(&mut self) -> Result<(), DeserializeError>;
+        fn visit_unit(&mut self) -> Result<(), DeserializeError>;
+    }
+
+    /// Holds a visitor until a handler calls into it, then holds its output
+    pub(crate) struct VisitorSlot<'de, V: Visitor<'de>> {
+        visitor: Option<V>,
+        value: Option<V::Value>,
+        _de: PhantomData<&'de ()>,
+    }
+
+    impl<'de, V: Visitor<'de>> VisitorSlot<'de, V> {
+        pub(crate) fn new(visitor: V) -> Self {
+            VisitorSlot {
+                visitor: Some(visitor),
+                value: None,
+                _de: PhantomData,
+            }
+        }
+
+        pub(crate) fn finish(self, type_name: &str) -> Result<V::Value, DeserializeError> {
+            self.value.ok_or_else(|| {
+                DeserializeError::custom(format!("handler for {type_name} did not visit a value"))
+            })
+        }
+
+        fn visit(
+            &mut self,
+            f: impl FnOnce(V) -> Result<V::Value, DeserializeError>,
+        ) -> Result<(), DeserializeError> {
+            let visitor = self
+                .visitor
+                .take()
+                .ok_or_else(|| DeserializeError::custom("a value was already visited"))?;
+
+            self.value = Some(f(visitor)?);
+            Ok(())
+        }
+    }
+
+    impl<'de, V: Visitor<'de>> ErasedVisitor for VisitorSlot<'de, V> {
+        fn visit_bool(&mut self, v: bool) -> Result<(), DeserializeError> {
+            self.visit(|visitor| visitor.visit_bool(v))
+        }
+
+        fn visit_i16(&mut self, v: i16) -> Result<(), DeserializeError> {
+            self.visit(|visitor| visitor.visit_i16(v))
+        }
+
+        fn visit_i32(&mut self, v: i32) -> Result<(), DeserializeError> {
+            self.visit(|visitor| visitor.visit_i32(v))
+        }
+
+        fn visit_i64(&mut self, v: i64) -> Result<(), DeserializeError> {
+            self.visit(|visitor| visitor.visit_i64(v))
+        }
+
+        fn visit_u32(&mut self, v: u32) -> Result<(), DeserializeError> {
+            self.visit(|visitor| visitor.visit_u32(v))
+        }
+
+        fn visit_u64(&mut self, v: u64) -> Result<(), DeserializeError> {
+            self.visit(|visitor| visitor.visit_u64(v))
+        }
+
+        fn visit_f32(&mut self, v: f32) -> Result<(), DeserializeError> {
+            self.visit(|visitor| visitor.visit_f32(v))
+        }
+
+        fn visit_f64(&mut self, v: f64) -> Result<(), DeserializeError> {
+            self.visit(|visitor| visitor.visit_f64(v))
+        }
+
+        fn visit_str(&mut self, v: &str) -> Result<(), DeserializeError> {
+            self.visit(|visitor| visitor.visit_str(v))
+        }
+
+        fn visit_string(&mut self, v: String) -> Result<(), DeserializeError> {
+            self.visit(|visitor| visitor.visit_string(v))
+        }
+
+        fn visit_bytes(&mut self, v: &[u8]) -> Result<(), DeserializeError> {
+            self.visit(|visitor| visitor.visit_bytes(v))
+        }
+
+        fn visit_none(&mut self) -> Result<(), DeserializeError> {
+            self.visit(|visitor| visitor.visit_none())
+        }
+
+        fn visit_unit(&mut self) -> Result<(), DeserializeError> {
+            self.visit(|visitor| visitor.visit_unit())
+        }
+    }
+}
+
+/// Which Postgres type to Rust primitive coercions are safe
+pub mod pg_type_compatibility {
+    /// How well a Postgres type converts into a Rust type
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Compatibility {
+        /// Every value converts without loss
+        Safe,
+        /// Values may lose precision, or not fit at all
+        Lossy,
+        /// The conversion is never valid
+        Incompatible,
+    }
+
+    /// Looks up how `pg_type` (as in `TypeInfo::name`, e.g. `"INT4"`) converts into
+    /// `rust_type`, which is one of the serde data model primitives: `"bool"`,
+    /// `"i8"` to `"i64"`, `"u8"` to `"u64"`, `"f32"`, `"f64"`, `"char"`, `"str"`,
+    /// `"String"`, `"bytes"` or `"byte_buf"`.
+    pub fn is_compatible(pg_type: &str, rust_type: &str) -> Compatibility {
+        use Compatibility::*;
+
+        match (pg_type, rust_type) {
+            // JSON values carry their own types, which serde checks
+            ("JSON" | "JSONB", _) => Safe,
+
+            ("BYTEA", "bytes" | "byte_buf") => Safe,
+            ("BYTEA", _) => Incompatible,
+
+            ("BOOL", "bool") => Safe,
+
+            ("INT2", "i16" | "i32" | "i64" | "f32" | "f64") => Safe,
+            ("INT2", "i8" | "u8" | "u16" | "u32" | "u64") => Lossy,
+
+            ("INT4", "i32" | "i64" | "f64") => Safe,
+            ("INT4", "i8" | "i16" | "u8" | "u16" | "u32" | "u64" | "f32") => Lossy,
+
+            ("INT8", "i64") => Safe,
+            ("INT8", "i8" | "i16" | "i32" | "u8" | "u16" | "u32" | "u64" | "f32" | "f64") => Lossy,
+
+            ("OID", "u32" | "u64" | "i64" | "f64") => Safe,
+            ("OID", "i8" | "i16" | "i32" | "u8" | "u16" | "f32") => Lossy,
+
+            ("INTERVAL", "i64" | "u64") => Safe,
+
+            ("pg_lsn", "u64") => Safe,
+            ("pg_lsn", "i64" | "f64") => Lossy,
+
+            ("xid" | "regclass" | "regtype", "u32" | "u64" | "i64" | "f64") => Safe,
+            ("xid" | "regclass" | "regtype", "i8" | "i16" | "i32" | "u8" | "u16" | "f32") => Lossy,
+            // Only the text format has the name, `sqlx::query` sends the OID
+            ("regclass" | "regtype", "String" | "str") => Incompatible,
+
+            ("MONEY", "i64") => Safe,
+            ("MONEY", "f64") => Lossy,
+
+            ("FLOAT4", "f32" | "f64") => Safe,
+            ("FLOAT8", "f64") => Safe,
+            ("FLOAT8", "f32") => Lossy,
+
+            (
+                "NUMERIC",
+                "i8" | "i16" | "i32" | "i64" | "u8" | "u16" | "u32" | "u64" | "f32" | "f64",
+            ) => Lossy,
+
+            (
+                "BOOL" | "INT2" | "INT4" | "INT8" | "OID" | "pg_lsn" | "xid" | "MONEY" | "FLOAT4"
+                | "FLOAT8" | "NUMERIC",
+                _,
+            ) => Incompatible,
+
+            // Everything else is deserialized from its string representation
+            ("CHAR" | "TEXT" | "VARCHAR" | "BPCHAR" | "NAME" | "citext", "char") => Lossy,
+            (_, "String" | "str") => Safe,
+
+            _ => Incompatible,
         }
     }
 }
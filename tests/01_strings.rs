@@ -67,3 +67,81 @@ async fn varchar_as_string_opt() {
 
     assert_eq!(rows, Some("a string".to_owned()));
 }
+
+#[tokio::test]
+async fn fixed_length_char_as_padded_string() {
+    let row: String = fetch_one("SELECT 'hello' :: CHAR(10) AS greeting")
+        .await
+        .unwrap();
+    assert_eq!(row, "hello     ");
+}
+
+#[tokio::test]
+async fn name_as_string() {
+    let row: String = fetch_one("SELECT relname FROM pg_class WHERE relname = 'pg_class'")
+        .await
+        .unwrap();
+    assert_eq!(row, "pg_class");
+}
+
+#[tokio::test]
+async fn catalog_names_as_strings() {
+    let rows: Vec<String> = fetch_all("SELECT relname FROM pg_class LIMIT 5")
+        .await
+        .unwrap();
+
+    assert_eq!(rows.len(), 5);
+    assert!(rows.iter().all(|name| !name.is_empty()));
+}
+
+#[tokio::test]
+async fn fixed_length_char_field_keeps_padding() {
+    #[derive(Debug, serde::Deserialize, PartialEq, Eq)]
+    struct Country {
+        code: String,
+        name: Option<String>,
+    }
+
+    let row: Country = fetch_one("SELECT 'PT' :: CHAR(3) AS code, NULL :: CHAR(8) AS name")
+        .await
+        .unwrap();
+
+    assert_eq!(
+        row,
+        Country {
+            code: "PT ".to_owned(),
+            name: None
+        }
+    );
+}
+
+#[tokio::test]
+async fn xml_as_string() {
+    let row: String = fetch_one("SELECT '<root/>' :: XML").await.unwrap();
+    assert_eq!(row, "<root/>");
+}
+
+#[tokio::test]
+async fn xml_field_as_string() {
+    #[derive(Debug, serde::Deserialize, PartialEq, Eq)]
+    struct Feed {
+        id: i32,
+        body: String,
+        extra: Option<String>,
+    }
+
+    let row: Feed = fetch_one(
+        "SELECT 1 AS id, '<feed><entry a=\"1\">é</entry></feed>' :: XML AS body, NULL :: XML AS extra",
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        row,
+        Feed {
+            id: 1,
+            body: "<feed><entry a=\"1\">é</entry></feed>".to_owned(),
+            extra: None
+        }
+    );
+}
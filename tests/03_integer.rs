@@ -1,5 +1,8 @@
 mod util;
 
+use std::fmt;
+
+use serde::de::{Deserialize, Deserializer, Visitor};
 use util::{fetch_one, fetch_optional};
 
 #[tokio::test]
@@ -64,3 +67,105 @@ async fn bigint_as_i64() {
     let row: i64 = fetch_one("SELECT 42 :: BIGINT AS value").await.unwrap();
     assert_eq!(row, 42);
 }
+
+#[tokio::test]
+async fn oid_as_u32() {
+    let row: u32 = fetch_one("SELECT oid FROM pg_class WHERE relname = 'pg_class'")
+        .await
+        .unwrap();
+    assert_eq!(row, 1259);
+}
+
+#[tokio::test]
+async fn oid_as_u32_opt() {
+    let row: Option<u32> = fetch_one("SELECT NULL :: OID AS value").await.unwrap();
+    assert_eq!(row, None);
+
+    let row: Option<u32> = fetch_one("SELECT oid FROM pg_class LIMIT 1").await.unwrap();
+    assert!(row.is_some());
+}
+
+#[tokio::test]
+async fn large_oid_as_u32() {
+    let row: u32 = fetch_one("SELECT 4294967295 :: OID AS value").await.unwrap();
+    assert_eq!(row, u32::MAX);
+}
+
+#[tokio::test]
+async fn oid_array_as_vec_of_u32() {
+    let row: Vec<u32> = fetch_one("SELECT ARRAY[23, 25] :: OID[] AS value")
+        .await
+        .unwrap();
+    assert_eq!(row, vec![23, 25]);
+}
+
+#[tokio::test]
+async fn whole_numeric_as_i64() {
+    let row: i64 = fetch_one("SELECT -42 :: NUMERIC(10, 0) AS value")
+        .await
+        .unwrap();
+    assert_eq!(row, -42);
+}
+
+#[tokio::test]
+async fn whole_numeric_as_u64() {
+    let row: u64 = fetch_one("SELECT 18446744073709551615 :: NUMERIC AS value")
+        .await
+        .unwrap();
+    assert_eq!(row, u64::MAX);
+}
+
+#[tokio::test]
+async fn fractional_numeric_as_i64_fails() {
+    fetch_one::<i64>("SELECT 1.5 :: NUMERIC AS value")
+        .await
+        .unwrap_err();
+}
+
+/// Only accepts the integer width it asks for, unlike serde's own visitors
+#[derive(Debug, PartialEq)]
+struct Strict<T>(T);
+
+macro_rules! strict {
+    ($ty:ty, $method:ident, $visit:ident) => {
+        impl<'de> Deserialize<'de> for Strict<$ty> {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                struct StrictVisitor;
+
+                impl<'de> Visitor<'de> for StrictVisitor {
+                    type Value = Strict<$ty>;
+
+                    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                        f.write_str(stringify!($ty))
+                    }
+
+                    fn $visit<E>(self, v: $ty) -> Result<Self::Value, E> {
+                        Ok(Strict(v))
+                    }
+                }
+
+                deserializer.$method(StrictVisitor)
+            }
+        }
+    };
+}
+
+strict!(i32, deserialize_i32, visit_i32);
+strict!(i64, deserialize_i64, visit_i64);
+
+#[tokio::test]
+async fn int2_widens_for_strict_visitors() {
+    let row: Strict<i32> = fetch_one("SELECT 42 :: INT2 AS value").await.unwrap();
+    assert_eq!(row, Strict(42));
+
+    let row: Strict<i64> = fetch_one("SELECT -42 :: INT2 AS value").await.unwrap();
+    assert_eq!(row, Strict(-42));
+}
+
+#[tokio::test]
+async fn int4_widens_for_strict_visitors() {
+    let row: Strict<i64> = fetch_one("SELECT 2147483647 :: INT4 AS value")
+        .await
+        .unwrap();
+    assert_eq!(row, Strict(2147483647));
+}
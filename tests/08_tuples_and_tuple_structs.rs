@@ -36,3 +36,57 @@ async fn a_one_item_tuple_unannotated() {
         .unwrap();
     assert_eq!(rows, vec![(true,), (false,)]);
 }
+
+#[tokio::test]
+async fn scalar_column_as_vec() {
+    let rows: Vec<Vec<i32>> = fetch_all("SELECT 42 :: INT4 AS value").await.unwrap();
+    assert_eq!(rows, vec![vec![42]]);
+}
+
+#[tokio::test]
+async fn scalar_column_as_vec_struct_field() {
+    #[derive(Debug, serde::Deserialize, PartialEq, Eq)]
+    struct Record {
+        ids: Vec<i32>,
+        names: (String,),
+    }
+
+    let rows: Vec<Record> = fetch_all("SELECT 42 :: INT4 AS ids, 'a string' AS names")
+        .await
+        .unwrap();
+    assert_eq!(
+        rows,
+        vec![Record {
+            ids: vec![42],
+            names: ("a string".to_owned(),),
+        }]
+    );
+}
+
+#[tokio::test]
+async fn one_tuple_per_row() {
+    let rows = util::fetch_rows("SELECT true, 42 UNION ALL SELECT false, 0").await;
+
+    let pairs: Vec<(bool, i32)> = serde_sqlx::from_pg_rows(rows).unwrap();
+    assert_eq!(pairs, vec![(true, 42), (false, 0)]);
+}
+
+#[tokio::test]
+async fn array_column_as_tuple_element() {
+    let rows: Vec<(Vec<i32>, String)> =
+        fetch_all("SELECT ARRAY[1, 2] AS ids, 'a' AS name UNION ALL SELECT '{}', 'b'")
+            .await
+            .unwrap();
+    assert_eq!(
+        rows,
+        vec![(vec![1, 2], "a".to_owned()), (vec![], "b".to_owned())]
+    );
+}
+
+#[tokio::test]
+async fn lone_array_column_as_one_vec_per_row() {
+    let rows: Vec<Vec<i32>> = fetch_all("SELECT ARRAY[1, 2] UNION ALL SELECT ARRAY[3]")
+        .await
+        .unwrap();
+    assert_eq!(rows, vec![vec![1, 2], vec![3]]);
+}
@@ -1,7 +1,10 @@
 mod util;
 
+use std::collections::LinkedList;
+
 use serde_json::Value as JsValue;
-use util::fetch_all;
+use sqlx::{postgres::PgTypeInfo, Column, Row};
+use util::{fetch_all, fetch_one, fetch_row};
 
 #[tokio::test]
 async fn pg_arr_of_bool_as_vec_bool() {
@@ -54,6 +57,42 @@ async fn pg_arr_of_varchar_as_vec_nullable_string() {
     assert_eq!(rows, vec![vec![Some("a string".to_owned()), None]]);
 }
 
+#[tokio::test]
+async fn pg_arr_of_int8_as_vec_nullable_i64() {
+    let rows: Vec<Vec<Option<i64>>> =
+        fetch_all("SELECT array_agg(R.a) _0 FROM (SELECT 42::INT8 a UNION ALL SELECT NULL) R")
+            .await
+            .unwrap();
+    assert_eq!(rows, vec![vec![Some(42), None]]);
+}
+
+#[tokio::test]
+async fn pg_arr_of_int2_as_vec_nullable_i16() {
+    let rows: Vec<Vec<Option<i16>>> =
+        fetch_all("SELECT array_agg(R.a) _0 FROM (SELECT 42::INT2 a UNION ALL SELECT NULL) R")
+            .await
+            .unwrap();
+    assert_eq!(rows, vec![vec![Some(42), None]]);
+}
+
+#[tokio::test]
+async fn pg_arr_of_float4_as_vec_nullable_f32() {
+    let rows: Vec<Vec<Option<f32>>> =
+        fetch_all("SELECT array_agg(R.a) _0 FROM (SELECT 1.5::FLOAT4 a UNION ALL SELECT NULL) R")
+            .await
+            .unwrap();
+    assert_eq!(rows, vec![vec![Some(1.5), None]]);
+}
+
+#[tokio::test]
+async fn pg_arr_of_float8_as_vec_nullable_f64() {
+    let rows: Vec<Vec<Option<f64>>> =
+        fetch_all("SELECT array_agg(R.a) _0 FROM (SELECT 1.5::FLOAT8 a UNION ALL SELECT NULL) R")
+            .await
+            .unwrap();
+    assert_eq!(rows, vec![vec![Some(1.5), None]]);
+}
+
 #[tokio::test]
 async fn pg_arr_of_jsonb_as_vec_of_jsvalue() {
     let rows: Vec<Vec<JsValue>> = fetch_all(
@@ -63,3 +102,408 @@ async fn pg_arr_of_jsonb_as_vec_of_jsvalue() {
     .unwrap();
     assert_eq!(rows, vec![vec![serde_json::json!(1), serde_json::json!(2)]]);
 }
+
+#[tokio::test]
+async fn pg_arr_of_uuid_as_vec_string() {
+    let row: Vec<String> = fetch_one(
+        "SELECT ARRAY['a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11', '00000000-0000-0000-0000-000000000000']::UUID[] AS value",
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        row,
+        vec![
+            "a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11".to_owned(),
+            "00000000-0000-0000-0000-000000000000".to_owned()
+        ]
+    );
+}
+
+#[tokio::test]
+async fn pg_arr_of_uuid_as_vec_uuid() {
+    let row: Vec<uuid::Uuid> =
+        fetch_one("SELECT ARRAY['a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11']::UUID[] AS value")
+            .await
+            .unwrap();
+    assert_eq!(
+        row,
+        vec![uuid::uuid!("a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11")]
+    );
+}
+
+#[tokio::test]
+async fn pg_arr_of_uuid_as_vec_nullable_uuid() {
+    let row: Vec<Option<uuid::Uuid>> =
+        fetch_one("SELECT ARRAY['a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11', NULL]::UUID[] AS value")
+            .await
+            .unwrap();
+    assert_eq!(
+        row,
+        vec![
+            Some(uuid::uuid!("a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11")),
+            None
+        ]
+    );
+}
+
+#[tokio::test]
+async fn pg_arr_of_date_as_vec_string() {
+    let row: Vec<String> = fetch_one("SELECT ARRAY['2024-01-31', '1999-12-01']::DATE[] AS value")
+        .await
+        .unwrap();
+    assert_eq!(row, vec!["2024-01-31".to_owned(), "1999-12-01".to_owned()]);
+}
+
+#[tokio::test]
+async fn pg_arr_of_date_as_vec_nullable_string() {
+    let row: Vec<Option<String>> = fetch_one("SELECT ARRAY['2024-01-31', NULL]::DATE[] AS value")
+        .await
+        .unwrap();
+    assert_eq!(row, vec![Some("2024-01-31".to_owned()), None]);
+}
+
+#[tokio::test]
+async fn pg_arr_of_time_as_vec_string() {
+    let row: Vec<String> = fetch_one("SELECT ARRAY['12:30:00', '23:59:59.5']::TIME[] AS value")
+        .await
+        .unwrap();
+    assert_eq!(row, vec!["12:30:00".to_owned(), "23:59:59.500".to_owned()]);
+}
+
+#[tokio::test]
+async fn pg_arr_of_time_as_vec_nullable_string() {
+    let row: Vec<Option<String>> =
+        fetch_all("SELECT array_agg(R.a) _0 FROM (SELECT '08:00'::TIME a UNION ALL SELECT NULL) R")
+            .await
+            .unwrap()
+            .remove(0);
+    assert_eq!(row, vec![Some("08:00:00".to_owned()), None]);
+}
+
+#[tokio::test]
+async fn pg_arr_of_timetz_as_vec_string() {
+    let row: Vec<String> = fetch_one("SELECT ARRAY['12:30:00+02']::TIMETZ[] AS value")
+        .await
+        .unwrap();
+    assert_eq!(row, vec!["12:30:00+02:00".to_owned()]);
+}
+
+#[tokio::test]
+async fn pg_arr_of_timestamp_as_vec_string() {
+    let row: Vec<String> = fetch_one("SELECT ARRAY['2024-01-31 12:30:00']::TIMESTAMP[] AS value")
+        .await
+        .unwrap();
+    assert_eq!(row, vec!["2024-01-31T12:30:00".to_owned()]);
+}
+
+#[tokio::test]
+async fn pg_arr_of_timestamptz_as_vec_nullable_string() {
+    let row: Vec<Option<String>> =
+        fetch_one("SELECT ARRAY['2024-01-31 12:30:00+02', NULL]::TIMESTAMPTZ[] AS value")
+            .await
+            .unwrap();
+    assert_eq!(
+        row,
+        vec![Some("2024-01-31T10:30:00+00:00".to_owned()), None]
+    );
+}
+
+#[tokio::test]
+async fn pg_arr_of_interval_as_vec_string() {
+    let row: Vec<String> = fetch_one(
+        "SELECT ARRAY['1 hour', '2 days 30 seconds', '1.5 seconds']::INTERVAL[] AS value",
+    )
+    .await
+    .unwrap();
+    let scalars: Vec<String> = fetch_one(
+        "SELECT '1 hour'::INTERVAL, '2 days 30 seconds'::INTERVAL, '1.5 seconds'::INTERVAL",
+    )
+    .await
+    .unwrap();
+    assert_eq!(row, scalars);
+    assert_eq!(row[0], "PT3600S");
+}
+
+#[tokio::test]
+async fn pg_arr_of_interval_as_vec_nullable_string() {
+    let row: Vec<Option<String>> = fetch_one("SELECT ARRAY['1 hour', NULL]::INTERVAL[] AS value")
+        .await
+        .unwrap();
+    assert_eq!(row, vec![Some("PT3600S".to_owned()), None]);
+}
+
+#[tokio::test]
+async fn pg_arr_of_bytea_as_vec_of_bytes() {
+    let row: Vec<Vec<u8>> =
+        fetch_one("SELECT ARRAY['\\x00ff10'::BYTEA, '\\x'::BYTEA, 'abc'::BYTEA] AS value")
+            .await
+            .unwrap();
+    assert_eq!(row, vec![vec![0x00, 0xff, 0x10], vec![], b"abc".to_vec()]);
+}
+
+#[tokio::test]
+async fn pg_arr_of_bytea_as_vec_of_nullable_bytes() {
+    let row: Vec<Option<Vec<u8>>> = fetch_one("SELECT ARRAY['\\xdeadbeef'::BYTEA, NULL] AS value")
+        .await
+        .unwrap();
+    assert_eq!(row, vec![Some(vec![0xde, 0xad, 0xbe, 0xef]), None]);
+}
+
+#[tokio::test]
+async fn pg_arr_of_numeric_as_vec_f64() {
+    let row: Vec<f64> = fetch_one("SELECT ARRAY[1.5, -20.25, 0]::NUMERIC[] AS value")
+        .await
+        .unwrap();
+    assert_eq!(row, vec![1.5, -20.25, 0.0]);
+}
+
+#[tokio::test]
+async fn pg_arr_of_numeric_as_vec_nullable_f64() {
+    let row: Vec<Option<f64>> =
+        fetch_all("SELECT array_agg(R.a) _0 FROM (SELECT 3.75::NUMERIC a UNION ALL SELECT NULL) R")
+            .await
+            .unwrap()
+            .remove(0);
+    assert_eq!(row, vec![Some(3.75), None]);
+}
+
+#[cfg(feature = "rust_decimal")]
+#[tokio::test]
+async fn pg_arr_of_numeric_as_vec_decimal() {
+    use std::str::FromStr;
+
+    use rust_decimal::Decimal;
+
+    let row: Vec<Option<Decimal>> =
+        fetch_one("SELECT ARRAY[12345678901234567890.123456789, NULL]::NUMERIC[] AS value")
+            .await
+            .unwrap();
+    assert_eq!(
+        row,
+        vec![
+            Some(Decimal::from_str("12345678901234567890.123456789").unwrap()),
+            None
+        ]
+    );
+}
+
+#[tokio::test]
+async fn pg_arr_cast_to_bigint_as_vec_i64() {
+    let row: Vec<i64> = fetch_one("SELECT ARRAY[1, 2, 3] :: BIGINT[] AS value")
+        .await
+        .unwrap();
+    assert_eq!(row, vec![1, 2, 3]);
+}
+
+#[tokio::test]
+async fn pg_arr_cast_to_real_as_vec_f32() {
+    let row: Vec<f32> = fetch_one("SELECT ARRAY[1.5, -2.5] :: REAL[] AS value")
+        .await
+        .unwrap();
+    assert_eq!(row, vec![1.5, -2.5]);
+}
+
+#[tokio::test]
+async fn pg_arr_cast_to_double_precision_as_vec_f64() {
+    let row: Vec<f64> = fetch_one("SELECT ARRAY[1.5, -2.5] :: DOUBLE PRECISION[] AS value")
+        .await
+        .unwrap();
+    assert_eq!(row, vec![1.5, -2.5]);
+}
+
+#[tokio::test]
+async fn pg_arr_of_int4_with_nulls_as_vec_nullable_i32() {
+    let row: Vec<Option<i32>> = fetch_one("SELECT ARRAY[1, NULL, 3] :: INT4[] AS value")
+        .await
+        .unwrap();
+    assert_eq!(row, vec![Some(1), None, Some(3)]);
+}
+
+#[tokio::test]
+async fn pg_empty_arr_of_int8_as_vec_i64() {
+    let row: Vec<i64> = fetch_one("SELECT '{}' :: INT8[] AS value").await.unwrap();
+    assert_eq!(row, Vec::<i64>::new());
+}
+
+#[tokio::test]
+async fn pg_large_arr_of_int4_as_vec_i32() {
+    let row: Vec<i32> = fetch_one("SELECT array_agg(g) FROM generate_series(1, 1000) g")
+        .await
+        .unwrap();
+    assert_eq!(row, (1..=1000).collect::<Vec<_>>());
+}
+
+#[tokio::test]
+async fn pg_arr_of_int4_as_linked_list_i32() {
+    let row: LinkedList<i32> = fetch_one("SELECT ARRAY[1, 2, 3] :: INT4[] AS value")
+        .await
+        .unwrap();
+    assert_eq!(row, LinkedList::from([1, 2, 3]));
+}
+
+#[tokio::test]
+async fn pg_arr_of_text_as_linked_list_string() {
+    let row: LinkedList<String> = fetch_one("SELECT ARRAY['a', 'b'] :: TEXT[] AS value")
+        .await
+        .unwrap();
+    assert_eq!(row, LinkedList::from(["a".to_owned(), "b".to_owned()]));
+}
+
+#[tokio::test]
+async fn pg_all_null_arr_of_int2_as_vec_nullable_i16() {
+    let row: Vec<Option<i16>> =
+        fetch_one("SELECT array_agg(x :: INT2) FROM (SELECT NULL :: INT2 x) t")
+            .await
+            .unwrap();
+    assert_eq!(row, vec![None]);
+}
+
+#[tokio::test]
+async fn pg_all_null_arr_of_int2_as_vec_i16_fails() {
+    let result =
+        fetch_one::<Vec<i16>>("SELECT array_agg(x :: INT2) FROM (SELECT NULL :: INT2 x) t").await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn pg_null_arr_of_int2_as_optional_vec_i16() {
+    // `array_agg` over an empty set is SQL NULL, not an empty array
+    let row: Option<Vec<i16>> =
+        fetch_one("SELECT array_agg(x) FROM (SELECT 1 :: INT2 x WHERE false) t")
+            .await
+            .unwrap();
+    assert_eq!(row, None);
+}
+
+#[tokio::test]
+async fn pg_null_arr_of_int2_as_vec_i16_fails() {
+    let result =
+        fetch_one::<Vec<i16>>("SELECT array_agg(x) FROM (SELECT 1 :: INT2 x WHERE false) t").await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn is_pg_array_type_checks_column_types() {
+    let row =
+        fetch_row("SELECT ARRAY[1, 2] a, 1 b, ARRAY[gen_random_uuid()] c, '{}'::JSONB d").await;
+    let is_array: Vec<bool> = row
+        .columns()
+        .iter()
+        .map(|col| serde_sqlx::is_pg_array_type(col.type_info()))
+        .collect();
+    assert_eq!(is_array, vec![true, false, true, false]);
+}
+
+#[test]
+fn is_pg_array_type_checks_oids() {
+    assert!(serde_sqlx::is_pg_array_type(&PgTypeInfo::with_oid(
+        sqlx::postgres::types::Oid(1007)
+    )));
+    assert!(!serde_sqlx::is_pg_array_type(&PgTypeInfo::with_oid(
+        sqlx::postgres::types::Oid(23)
+    )));
+}
+
+#[tokio::test]
+async fn two_dimensional_int4_array() {
+    let row: Vec<Vec<i32>> = fetch_one("SELECT ARRAY[[1, 2, 3], [4, 5, 6]] :: INT4[][]")
+        .await
+        .unwrap();
+    assert_eq!(row, vec![vec![1, 2, 3], vec![4, 5, 6]]);
+}
+
+#[tokio::test]
+async fn two_dimensional_text_array() {
+    let row: Vec<Vec<String>> = fetch_one("SELECT ARRAY[['a', 'b'], ['c', 'd']] :: TEXT[][]")
+        .await
+        .unwrap();
+    assert_eq!(row, vec![vec!["a", "b"], vec!["c", "d"]]);
+}
+
+#[tokio::test]
+async fn three_dimensional_array_with_nulls() {
+    let row: Vec<Vec<Vec<Option<i64>>>> =
+        fetch_one("SELECT ARRAY[[[1, NULL]], [[NULL, 4]]] :: INT8[]")
+            .await
+            .unwrap();
+    assert_eq!(
+        row,
+        vec![vec![vec![Some(1), None]], vec![vec![None, Some(4)]]]
+    );
+}
+
+#[tokio::test]
+async fn two_dimensional_arrays_as_fixed_size_arrays() {
+    let row: [[bool; 2]; 2] = fetch_one("SELECT ARRAY[[true, false], [false, true]]")
+        .await
+        .unwrap();
+    assert_eq!(row, [[true, false], [false, true]]);
+
+    assert!(fetch_one::<Vec<[i32; 1]>>("SELECT ARRAY[[1, 2], [3, 4]]")
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn two_dimensional_array_struct_field() {
+    #[derive(Debug, serde::Deserialize, PartialEq)]
+    struct Board {
+        name: String,
+        cells: Vec<Vec<f64>>,
+        empty: Vec<Vec<f64>>,
+    }
+
+    let row: Board = fetch_one(
+        "SELECT 'b' AS name, ARRAY[[0.5, 1], [2, 3]] :: FLOAT8[][] AS cells, '{}' :: FLOAT8[][] AS empty",
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        row,
+        Board {
+            name: "b".to_owned(),
+            cells: vec![vec![0.5, 1.0], vec![2.0, 3.0]],
+            empty: vec![],
+        }
+    );
+}
+
+#[tokio::test]
+async fn unsupported_multi_dimensional_array() {
+    let err = fetch_one::<Vec<Vec<String>>>("SELECT ARRAY[['2024-01-01'::DATE]]")
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("Multi-dimensional"), "{err}");
+}
+
+#[tokio::test]
+async fn null_array_into_option() {
+    let ints: Option<Vec<i32>> = fetch_one("SELECT NULL::INT4[]").await.unwrap();
+    assert_eq!(ints, None);
+
+    let texts: Option<LinkedList<String>> = fetch_one("SELECT NULL::TEXT[]").await.unwrap();
+    assert_eq!(texts, None);
+
+    let rows: Vec<Option<Vec<i64>>> =
+        fetch_all("SELECT ARRAY[1::INT8] UNION ALL SELECT NULL::INT8[]")
+            .await
+            .unwrap();
+    assert_eq!(rows, vec![Some(vec![1]), None]);
+}
+
+#[tokio::test]
+async fn null_array_needs_an_option() {
+    for query in [
+        "SELECT NULL::INT4[] AS ids",
+        "SELECT NULL::TEXT[] AS ids",
+        "SELECT NULL::JSONB[] AS ids",
+    ] {
+        let err = fetch_one::<Vec<JsValue>>(query).await.unwrap_err();
+        assert!(
+            err.to_string()
+                .starts_with("failed to deserialize column 'ids'"),
+            "{err}"
+        );
+        assert!(err.to_string().contains("invalid type: Option value"), "{err}");
+    }
+}
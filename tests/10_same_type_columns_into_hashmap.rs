@@ -1,6 +1,6 @@
 mod util;
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use util::fetch_all;
 
 #[tokio::test]
@@ -32,7 +32,6 @@ async fn int4_columns_into_hashmap() {
 }
 
 #[tokio::test]
-#[ignore]
 async fn int4_columns_into_hashmap_tuple_idx_prefix() {
     let rows: Vec<(HashMap<String, i32>, i32)> =
         fetch_all("SELECT 1 _0_one, 2 _0_two, 3 _0_three, 4 _1")
@@ -48,3 +47,52 @@ async fn int4_columns_into_hashmap_tuple_idx_prefix() {
 
     assert_eq!(rows, vec![(expected_hashmap, 4)]);
 }
+
+#[tokio::test]
+async fn text_columns_into_btreemap() {
+    let rows: Vec<BTreeMap<String, String>> = fetch_all("SELECT '1' one, '2' two, '3' three")
+        .await
+        .unwrap();
+    let expected: BTreeMap<String, String> = BTreeMap::from([
+        ("one".to_owned(), "1".to_owned()),
+        ("two".to_owned(), "2".to_owned()),
+        ("three".to_owned(), "3".to_owned()),
+    ]);
+    assert_eq!(rows, vec![expected]);
+}
+
+#[tokio::test]
+async fn int4_columns_into_btreemap() {
+    let rows: Vec<BTreeMap<String, i32>> = fetch_all("SELECT 1 one, 2 two, 3 three").await.unwrap();
+    let expected: BTreeMap<String, i32> = BTreeMap::from([
+        ("one".to_owned(), 1),
+        ("two".to_owned(), 2),
+        ("three".to_owned(), 3),
+    ]);
+    assert_eq!(rows, vec![expected]);
+}
+
+#[tokio::test]
+async fn mixed_columns_into_btreemap_of_json_values() {
+    let rows: Vec<BTreeMap<String, serde_json::Value>> =
+        fetch_all("SELECT 1 id, 'alice' name, NULL::BOOL active, ARRAY[1, 2] ids")
+            .await
+            .unwrap();
+    let expected: BTreeMap<String, serde_json::Value> = BTreeMap::from([
+        ("id".to_owned(), serde_json::json!(1)),
+        ("name".to_owned(), serde_json::json!("alice")),
+        ("active".to_owned(), serde_json::Value::Null),
+        ("ids".to_owned(), serde_json::json!([1, 2])),
+    ]);
+    assert_eq!(rows, vec![expected]);
+}
+
+#[tokio::test]
+async fn int4_columns_into_btreemap_tuple_idx_prefix() {
+    let rows: Vec<(BTreeMap<String, i32>, i32)> =
+        fetch_all("SELECT 1 _0_one, 2 _0_two, 4 _1").await.unwrap();
+    let expected: BTreeMap<String, i32> =
+        BTreeMap::from([("one".to_owned(), 1), ("two".to_owned(), 2)]);
+
+    assert_eq!(rows, vec![(expected, 4)]);
+}
@@ -38,7 +38,6 @@ async fn int4_columns_into_struct_with_flattenned_field() {
 }
 
 #[tokio::test]
-#[ignore]
 async fn int4_columns_into_struct_with_flattened_field_with_tuple_idx_prefix() {
     #[derive(Debug, ::serde::Deserialize, PartialEq, Eq)]
     struct Record {
@@ -54,3 +53,63 @@ async fn int4_columns_into_struct_with_flattened_field_with_tuple_idx_prefix() {
         .collect();
     assert_eq!(rows, vec![(Record { one: 1, the_rest }, 4)]);
 }
+
+#[tokio::test]
+async fn several_flattened_structs_share_the_columns() {
+    #[derive(Debug, ::serde::Deserialize, PartialEq)]
+    struct Audit {
+        created: chrono::NaiveDate,
+        note: Option<String>,
+    }
+
+    #[derive(Debug, ::serde::Deserialize, PartialEq)]
+    struct Owner {
+        owner_id: i64,
+        tags: Vec<String>,
+    }
+
+    #[derive(Debug, ::serde::Deserialize, PartialEq)]
+    struct Ownership {
+        #[serde(flatten)]
+        owner: Owner,
+    }
+
+    #[derive(Debug, ::serde::Deserialize, PartialEq)]
+    struct Archive {
+        archived_at: chrono::NaiveDate,
+    }
+
+    #[derive(Debug, ::serde::Deserialize, PartialEq)]
+    struct Record {
+        id: i32,
+        #[serde(flatten)]
+        audit: Audit,
+        #[serde(flatten)]
+        ownership: Ownership,
+        #[serde(flatten)]
+        archive: Option<Archive>,
+    }
+
+    let rows: Vec<Record> = fetch_all(
+        "SELECT 1 id, '2024-01-01'::DATE created, NULL::TEXT note, 7::INT8 owner_id, ARRAY['a'] tags",
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        rows,
+        vec![Record {
+            id: 1,
+            audit: Audit {
+                created: chrono::NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+                note: None,
+            },
+            ownership: Ownership {
+                owner: Owner {
+                    owner_id: 7,
+                    tags: vec!["a".to_owned()],
+                },
+            },
+            archive: None,
+        }]
+    );
+}
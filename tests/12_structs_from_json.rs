@@ -33,7 +33,67 @@ async fn single_json_field_into_a_record() {
 }
 
 #[tokio::test]
-#[ignore = "TODO: doesn't work yet"]
+async fn single_jsonb_field_into_a_record_with_defaults() {
+    #[derive(Debug, Deserialize, PartialEq, Eq)]
+    struct Record {
+        one: i32,
+        #[serde(default)]
+        two: i32,
+        three: Option<i32>,
+    }
+
+    let row: Record = util::fetch_one(r#"SELECT '{"one": 1}' :: JSONB AS payload"#)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        row,
+        Record {
+            one: 1,
+            two: 0,
+            three: None
+        }
+    );
+}
+
+#[tokio::test]
+async fn single_json_field_missing_a_required_key() {
+    #[derive(Debug, Deserialize)]
+    #[allow(dead_code)]
+    struct Record {
+        one: i32,
+        two: i32,
+    }
+
+    let err = util::fetch_one::<Record>(r#"SELECT '{"one": 1}' :: JSON"#)
+        .await
+        .unwrap_err();
+
+    assert!(err.to_string().contains("missing field `two`"), "{err}");
+}
+
+#[tokio::test]
+async fn json_column_first_among_several() {
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Record {
+        payload: JsValue,
+        id: i32,
+    }
+
+    let row: Record = util::fetch_one(r#"SELECT '{"a": 1}' :: JSON AS payload, 7 AS id"#)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        row,
+        Record {
+            payload: serde_json::json!({ "a": 1 }),
+            id: 7
+        }
+    );
+}
+
+#[tokio::test]
 async fn a_record_with_vec_of_js_value_fields() {
     #[derive(Debug, serde::Deserialize, PartialEq, Eq)]
     struct Record {
@@ -98,3 +158,218 @@ async fn single_json_field_into_a_record_field() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn single_jsonb_field_into_a_js_value_record_field() -> anyhow::Result<()> {
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct JsonRecord {
+        payload: JsValue,
+    }
+
+    let out: JsonRecord = util::fetch_one(
+        r#"
+            SELECT '{"nested": {"list": [1, 2.5, null, "three"]}, "flag": true}' :: JSONB payload
+        "#,
+    )
+    .await?;
+
+    assert_eq!(
+        out,
+        JsonRecord {
+            payload: serde_json::json!({
+                "nested": { "list": [1, 2.5, null, "three"] },
+                "flag": true
+            })
+        }
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn jsonb_array_into_vec_of_js_values() {
+    let row: Vec<JsValue> = util::fetch_one(r#"SELECT '[1, "two", null]' :: JSONB AS arr"#)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        row,
+        vec![serde_json::json!(1), serde_json::json!("two"), JsValue::Null]
+    );
+}
+
+#[tokio::test]
+async fn json_array_into_vec_of_i32() {
+    let row: Vec<i32> = util::fetch_one("SELECT '[1, 2, 3]' :: JSON AS arr")
+        .await
+        .unwrap();
+
+    assert_eq!(row, vec![1, 2, 3]);
+}
+
+#[tokio::test]
+async fn jsonb_array_of_objects_into_vec_of_structs() {
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Item {
+        id: i32,
+    }
+
+    let rows: Vec<Vec<Item>> =
+        util::fetch_all(r#"SELECT '[{"id": 1}, {"id": 2}]' :: JSONB AS items"#)
+            .await
+            .unwrap();
+
+    assert_eq!(rows, vec![vec![Item { id: 1 }, Item { id: 2 }]]);
+}
+
+#[tokio::test]
+async fn jsonb_object_into_one_element_tuple() {
+    let row: (JsValue,) = util::fetch_one(r#"SELECT '{"a": 1}' :: JSONB AS payload"#)
+        .await
+        .unwrap();
+
+    assert_eq!(row, (serde_json::json!({ "a": 1 }),));
+}
+
+#[tokio::test]
+async fn jsonb_number_into_i32() {
+    let row: i32 = util::fetch_one("SELECT '42' :: JSONB AS value").await.unwrap();
+
+    assert_eq!(row, 42);
+}
+
+#[tokio::test]
+async fn jsonb_string_into_string() {
+    let row: String = util::fetch_one(r#"SELECT '"hello"' :: JSONB AS value"#)
+        .await
+        .unwrap();
+
+    assert_eq!(row, "hello");
+}
+
+#[tokio::test]
+async fn json_bool_into_bool() {
+    let row: bool = util::fetch_one("SELECT 'true' :: JSON AS value").await.unwrap();
+
+    assert!(row);
+}
+
+#[tokio::test]
+async fn jsonb_scalars_into_struct_fields() {
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Record {
+        count: i64,
+        ratio: f64,
+        label: Option<String>,
+    }
+
+    let row: Record = util::fetch_one(
+        r#"SELECT '3' :: JSONB AS count, '0.5' :: JSONB AS ratio, 'null' :: JSONB AS label"#,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        row,
+        Record {
+            count: 3,
+            ratio: 0.5,
+            label: None
+        }
+    );
+}
+
+#[tokio::test]
+async fn jsonb_object_and_null_into_optional_struct_fields() {
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Inner {
+        one: i32,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Record {
+        present: Option<Inner>,
+        json_null: Option<Inner>,
+        sql_null: Option<Inner>,
+    }
+
+    let row: Record = util::fetch_one(
+        r#"SELECT '{"one": 1}' :: JSONB AS present, 'null' :: JSONB AS json_null, NULL :: JSONB AS sql_null"#,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        row,
+        Record {
+            present: Some(Inner { one: 1 }),
+            json_null: None,
+            sql_null: None
+        }
+    );
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Inner {
+    id: i32,
+    kind: Kind,
+    label: Label,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+enum Kind {
+    Small,
+    Large { size: u32 },
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Label(String);
+
+#[tokio::test]
+async fn jsonb_array_column_into_vec_of_structs() {
+    let items: Vec<Inner> = util::fetch_one(
+        r#"SELECT ARRAY['{"id": 1, "kind": "small", "label": "a"}', '{"id": 2, "kind": {"large": {"size": 9}}, "label": "b"}']::JSONB[] AS items"#,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        items,
+        vec![
+            Inner {
+                id: 1,
+                kind: Kind::Small,
+                label: Label("a".to_owned())
+            },
+            Inner {
+                id: 2,
+                kind: Kind::Large { size: 9 },
+                label: Label("b".to_owned())
+            },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn json_array_elements_into_enums_and_newtypes() {
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Record {
+        kinds: Vec<Kind>,
+        labels: Vec<Option<Label>>,
+    }
+
+    let row: Record = util::fetch_one(
+        r#"SELECT ARRAY['"small"', '{"large": {"size": 1}}']::JSON[] AS kinds, ARRAY['"x"', NULL]::JSONB[] AS labels"#,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        row,
+        Record {
+            kinds: vec![Kind::Small, Kind::Large { size: 1 }],
+            labels: vec![Some(Label("x".to_owned())), None],
+        }
+    );
+}
@@ -120,3 +120,113 @@ async fn test_query_stats_from_json_field() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct RenamedStats {
+    #[serde(rename = "queryText")]
+    query_text: String,
+    #[serde(alias = "numCalls")]
+    calls: i64,
+}
+
+#[tokio::test]
+async fn renamed_field_matches_quoted_column() {
+    let stats: RenamedStats = fetch_one(r#"SELECT 'SELECT 1' AS "queryText", 3 :: INT8 AS calls"#)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        stats,
+        RenamedStats {
+            query_text: "SELECT 1".to_owned(),
+            calls: 3
+        }
+    );
+}
+
+#[tokio::test]
+async fn aliased_field_matches_alternative_column() {
+    let stats: RenamedStats =
+        fetch_one(r#"SELECT 'SELECT 1' AS "queryText", 3 :: INT8 AS "numCalls""#)
+            .await
+            .unwrap();
+
+    assert_eq!(stats.calls, 3);
+}
+
+#[tokio::test]
+async fn renamed_field_does_not_match_original_name() {
+    let result =
+        fetch_one::<RenamedStats>("SELECT 'SELECT 1' AS query_text, 3 :: INT8 AS calls").await;
+
+    assert!(result.is_err());
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Account {
+    id: i32,
+    #[serde(default)]
+    last_login: Option<String>,
+    #[serde(default)]
+    login_count: i64,
+}
+
+#[tokio::test]
+async fn default_fields_fill_in_for_missing_columns() {
+    let account: Account = fetch_one("SELECT 1 AS id").await.unwrap();
+
+    assert_eq!(
+        account,
+        Account {
+            id: 1,
+            last_login: None,
+            login_count: 0
+        }
+    );
+}
+
+#[tokio::test]
+async fn default_option_field_with_null_column() {
+    let account: Account =
+        fetch_one("SELECT 1 AS id, NULL :: TEXT AS last_login, 5 :: INT8 AS login_count")
+            .await
+            .unwrap();
+
+    assert_eq!(account.last_login, None);
+    assert_eq!(account.login_count, 5);
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct WithSkipped {
+    id: i32,
+    #[serde(skip)]
+    cached: String,
+    #[serde(skip_deserializing)]
+    derived: i64,
+}
+
+#[tokio::test]
+async fn skipped_fields_ignore_matching_columns() {
+    // 'NaN' can't be decoded as a number, which shows the columns aren't decoded at all
+    let record: WithSkipped =
+        fetch_one("SELECT 1 AS id, 'from the db' AS cached, 'NaN' :: NUMERIC AS derived")
+            .await
+            .unwrap();
+
+    assert_eq!(
+        record,
+        WithSkipped {
+            id: 1,
+            cached: String::new(),
+            derived: 0
+        }
+    );
+}
+
+#[tokio::test]
+async fn skipped_fields_need_no_columns() {
+    let record: WithSkipped = fetch_one("SELECT 1 AS id").await.unwrap();
+
+    assert_eq!(record.cached, "");
+    assert_eq!(record.derived, 0);
+}
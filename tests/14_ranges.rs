@@ -0,0 +1,270 @@
+mod util;
+
+use serde::Deserialize;
+use serde_sqlx::PgRange;
+use util::fetch_one;
+
+#[tokio::test]
+async fn pg_arr_of_int4range_as_vec_of_ranges() {
+    let row: Vec<PgRange<i32>> =
+        fetch_one("SELECT ARRAY['[1,10)'::INT4RANGE, '[5,)'::INT4RANGE] AS value")
+            .await
+            .unwrap();
+
+    assert_eq!(
+        row,
+        vec![
+            PgRange {
+                lower: Some(1),
+                upper: Some(10),
+                lower_inclusive: true,
+                upper_inclusive: false,
+            },
+            PgRange {
+                lower: Some(5),
+                upper: None,
+                lower_inclusive: true,
+                upper_inclusive: false,
+            },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn pg_arr_of_tstzrange_as_vec_of_ranges() {
+    let row: Vec<PgRange<String>> = fetch_one(
+        "SELECT ARRAY['[2024-01-01 00:00:00+00,2024-02-01 00:00:00+00)'::TSTZRANGE] AS value",
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        row,
+        vec![PgRange {
+            lower: Some("2024-01-01T00:00:00+00:00".to_owned()),
+            upper: Some("2024-02-01T00:00:00+00:00".to_owned()),
+            lower_inclusive: true,
+            upper_inclusive: false,
+        }]
+    );
+}
+
+#[tokio::test]
+async fn pg_arr_of_nullable_int4range_as_vec_of_optional_ranges() {
+    let row: Vec<Option<PgRange<i32>>> = fetch_one("SELECT ARRAY['(1,3]'::INT4RANGE, NULL] AS value")
+        .await
+        .unwrap();
+
+    assert_eq!(
+        row,
+        vec![
+            // Postgres canonicalizes discrete ranges to `[lower, upper)`
+            Some(PgRange {
+                lower: Some(2),
+                upper: Some(4),
+                lower_inclusive: true,
+                upper_inclusive: false,
+            }),
+            None,
+        ]
+    );
+}
+
+#[tokio::test]
+async fn int4range() {
+    let row: PgRange<i32> = fetch_one("SELECT '[1,10)'::INT4RANGE AS value")
+        .await
+        .unwrap();
+
+    assert_eq!(
+        row,
+        PgRange {
+            lower: Some(1),
+            upper: Some(10),
+            lower_inclusive: true,
+            upper_inclusive: false,
+        }
+    );
+}
+
+#[tokio::test]
+async fn int8range() {
+    let row: PgRange<i64> = fetch_one("SELECT '[10000000000,)'::INT8RANGE AS value")
+        .await
+        .unwrap();
+
+    assert_eq!(
+        row,
+        PgRange {
+            lower: Some(10_000_000_000),
+            upper: None,
+            lower_inclusive: true,
+            upper_inclusive: false,
+        }
+    );
+}
+
+#[tokio::test]
+async fn numrange() {
+    let row: PgRange<f64> = fetch_one("SELECT '(1.5,2.25]'::NUMRANGE AS value")
+        .await
+        .unwrap();
+
+    assert_eq!(
+        row,
+        PgRange {
+            lower: Some(1.5),
+            upper: Some(2.25),
+            lower_inclusive: false,
+            upper_inclusive: true,
+        }
+    );
+}
+
+#[tokio::test]
+async fn daterange() {
+    let row: PgRange<chrono::NaiveDate> =
+        fetch_one("SELECT '[2024-01-01,2024-02-01)'::DATERANGE AS value")
+            .await
+            .unwrap();
+
+    assert_eq!(
+        row,
+        PgRange {
+            lower: Some(chrono::NaiveDate::from_ymd_opt(2024, 1, 1).unwrap()),
+            upper: Some(chrono::NaiveDate::from_ymd_opt(2024, 2, 1).unwrap()),
+            lower_inclusive: true,
+            upper_inclusive: false,
+        }
+    );
+}
+
+#[tokio::test]
+async fn tsrange() {
+    let row: PgRange<String> =
+        fetch_one("SELECT '[2024-01-01 08:00,2024-01-01 17:00]'::TSRANGE AS value")
+            .await
+            .unwrap();
+
+    assert_eq!(
+        row,
+        PgRange {
+            lower: Some("2024-01-01T08:00:00".to_owned()),
+            upper: Some("2024-01-01T17:00:00".to_owned()),
+            lower_inclusive: true,
+            upper_inclusive: true,
+        }
+    );
+}
+
+#[tokio::test]
+async fn tstzrange() {
+    let row: PgRange<chrono::DateTime<chrono::Utc>> =
+        fetch_one("SELECT '[2024-01-01 00:00:00+00,)'::TSTZRANGE AS value")
+            .await
+            .unwrap();
+
+    assert_eq!(
+        row,
+        PgRange {
+            lower: Some("2024-01-01T00:00:00Z".parse().unwrap()),
+            upper: None,
+            lower_inclusive: true,
+            upper_inclusive: false,
+        }
+    );
+}
+
+#[tokio::test]
+async fn empty_range() {
+    let row: PgRange<i32> = fetch_one("SELECT 'empty'::INT4RANGE AS value")
+        .await
+        .unwrap();
+
+    assert_eq!(
+        row,
+        PgRange {
+            lower: None,
+            upper: None,
+            lower_inclusive: false,
+            upper_inclusive: false,
+        }
+    );
+}
+
+#[tokio::test]
+async fn struct_with_range_field() {
+    #[derive(Debug, Deserialize, PartialEq, Eq)]
+    struct Booking {
+        id: i32,
+        during: PgRange<chrono::NaiveDate>,
+    }
+
+    let row: Booking =
+        fetch_one("SELECT 1 AS id, '[2024-03-01,2024-03-08)'::DATERANGE AS during")
+            .await
+            .unwrap();
+
+    assert_eq!(
+        row,
+        Booking {
+            id: 1,
+            during: PgRange {
+                lower: Some(chrono::NaiveDate::from_ymd_opt(2024, 3, 1).unwrap()),
+                upper: Some(chrono::NaiveDate::from_ymd_opt(2024, 3, 8).unwrap()),
+                lower_inclusive: true,
+                upper_inclusive: false,
+            },
+        }
+    );
+}
+
+#[tokio::test]
+async fn aggregated_int4range_array() {
+    let row: Vec<PgRange<i32>> = fetch_one(
+        "SELECT array_agg(r)::INT4RANGE[] \
+         FROM (VALUES ('[1,3)'::INT4RANGE), ('[3,)'), ('empty')) AS ranges(r)",
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(row.len(), 3);
+    assert_eq!((row[0].lower, row[0].upper), (Some(1), Some(3)));
+    assert_eq!((row[1].lower, row[1].upper), (Some(3), None));
+}
+
+#[tokio::test]
+async fn struct_with_range_array_fields() {
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Schedule {
+        slots: Vec<PgRange<i64>>,
+        days: Vec<Option<PgRange<chrono::NaiveDate>>>,
+        shifts: Vec<PgRange<chrono::NaiveDateTime>>,
+        budgets: Vec<PgRange<f64>>,
+    }
+
+    let row: Schedule = fetch_one(
+        "SELECT ARRAY['[1,5)'::INT8RANGE] AS slots, \
+                ARRAY['[2024-03-01,2024-03-02]'::DATERANGE, NULL] AS days, \
+                ARRAY['[2024-03-01 09:00,2024-03-01 17:00)'::TSRANGE] AS shifts, \
+                ARRAY['[0.5,1.5]'::NUMRANGE] AS budgets",
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(row.slots[0].upper, Some(5));
+    assert_eq!(
+        row.days[0].as_ref().unwrap().upper,
+        // Discrete ranges are canonicalized to an exclusive upper bound
+        Some(chrono::NaiveDate::from_ymd_opt(2024, 3, 3).unwrap())
+    );
+    assert_eq!(row.days[1], None);
+    assert_eq!(
+        row.shifts[0].lower,
+        chrono::NaiveDate::from_ymd_opt(2024, 3, 1)
+            .unwrap()
+            .and_hms_opt(9, 0, 0)
+    );
+    assert_eq!(row.budgets[0].lower, Some(0.5));
+    assert!(row.budgets[0].upper_inclusive);
+}
@@ -0,0 +1,23 @@
+mod util;
+
+use util::fetch_row;
+
+#[tokio::test]
+async fn display_known_row() {
+    let row = fetch_row("SELECT 1 a, 'hello' b, NULL :: TEXT c, true d, 2.5 :: FLOAT8 e").await;
+
+    assert_eq!(
+        serde_sqlx::debug_row(&row).to_string(),
+        "{a: 1, b: hello, c: NULL, d: true, e: 2.5}"
+    );
+}
+
+#[tokio::test]
+async fn debug_known_row() {
+    let row = fetch_row("SELECT 1 a, 'hello' b, NULL :: TEXT c").await;
+
+    assert_eq!(
+        format!("{:?}", serde_sqlx::debug_row(&row)),
+        r#"{"a": "1", "b": "hello", "c": "NULL"}"#
+    );
+}
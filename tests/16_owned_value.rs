@@ -0,0 +1,31 @@
+mod util;
+
+use serde_sqlx::OwnedPgValue;
+use sqlx::Row;
+use util::fetch_row;
+
+#[tokio::test]
+async fn cloned_owned_value_deserializes_the_same() {
+    let row = fetch_row("SELECT 'a string' :: TEXT AS greeting").await;
+    let owned = OwnedPgValue::new(row.try_get_raw(0).unwrap());
+    let cloned = owned.clone();
+
+    let original: String = owned.deserialize().unwrap();
+    let copy: String = cloned.deserialize().unwrap();
+
+    assert_eq!(original, "a string");
+    assert_eq!(original, copy);
+}
+
+#[tokio::test]
+async fn owned_value_outlives_the_row() {
+    let row = fetch_row("SELECT 42 :: INT8 AS value, NULL :: INT4 AS missing").await;
+    let value = OwnedPgValue::new(row.try_get_raw(0).unwrap());
+    let missing = OwnedPgValue::new(row.try_get_raw(1).unwrap());
+    drop(row);
+
+    // The same value can be deserialized several times
+    assert_eq!(value.deserialize::<i64>().unwrap(), 42);
+    assert_eq!(value.deserialize::<i64>().unwrap(), 42);
+    assert_eq!(missing.deserialize::<Option<i32>>().unwrap(), None);
+}
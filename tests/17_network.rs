@@ -0,0 +1,124 @@
+mod util;
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use serde::Deserialize;
+use util::fetch_one;
+
+#[tokio::test]
+async fn inet_as_ip_addr() {
+    let row: IpAddr = fetch_one("SELECT '127.0.0.1' :: INET AS addr")
+        .await
+        .unwrap();
+    assert_eq!(row, IpAddr::V4(Ipv4Addr::LOCALHOST));
+}
+
+#[tokio::test]
+async fn inet_as_string() {
+    let row: String = fetch_one("SELECT '127.0.0.1' :: INET AS addr")
+        .await
+        .unwrap();
+    assert_eq!(row, "127.0.0.1");
+}
+
+#[tokio::test]
+async fn inet_with_netmask_as_string() {
+    let row: String = fetch_one("SELECT '192.168.0.1/24' :: INET AS addr")
+        .await
+        .unwrap();
+    assert_eq!(row, "192.168.0.1/24");
+}
+
+#[tokio::test]
+async fn inet_with_deserialize_with() {
+    #[derive(Debug, Deserialize, PartialEq, Eq)]
+    struct Host {
+        #[serde(deserialize_with = "serde_sqlx::inet::deserialize")]
+        addr: IpAddr,
+    }
+
+    let row: Host = fetch_one("SELECT '127.0.0.1' :: INET AS addr")
+        .await
+        .unwrap();
+    assert_eq!(
+        row,
+        Host {
+            addr: IpAddr::V4(Ipv4Addr::LOCALHOST)
+        }
+    );
+}
+
+#[tokio::test]
+async fn ipv6_inet_as_ip_addr() {
+    let row: IpAddr = fetch_one("SELECT '::1' :: INET AS addr").await.unwrap();
+    assert_eq!(row, IpAddr::V6(Ipv6Addr::LOCALHOST));
+}
+
+#[tokio::test]
+async fn ipv6_inet_with_netmask_as_string() {
+    let row: String = fetch_one("SELECT '2001:db8::1/64' :: INET AS addr")
+        .await
+        .unwrap();
+    assert_eq!(row, "2001:db8::1/64");
+}
+
+#[tokio::test]
+async fn cidr_as_string() {
+    let row: String = fetch_one("SELECT '192.168.0.0/24' :: CIDR AS network")
+        .await
+        .unwrap();
+    assert_eq!(row, "192.168.0.0/24");
+}
+
+#[tokio::test]
+async fn cidr_keeps_full_length_netmask() {
+    let row: String = fetch_one("SELECT '10.0.0.1' :: CIDR AS network")
+        .await
+        .unwrap();
+    assert_eq!(row, "10.0.0.1/32");
+}
+
+#[tokio::test]
+async fn ipv6_cidr_as_string() {
+    let row: String = fetch_one("SELECT '2001:db8::/32' :: CIDR AS network")
+        .await
+        .unwrap();
+    assert_eq!(row, "2001:db8::/32");
+}
+
+#[tokio::test]
+async fn macaddr_as_string() {
+    let row: String = fetch_one("SELECT '08:00:2b:01:02:03' :: MACADDR AS mac")
+        .await
+        .unwrap();
+    assert_eq!(row, "08:00:2b:01:02:03");
+}
+
+#[tokio::test]
+async fn macaddr8_as_string() {
+    let row: String = fetch_one("SELECT '08:00:2b:01:02:03:04:05' :: MACADDR8 AS mac")
+        .await
+        .unwrap();
+    assert_eq!(row, "08:00:2b:01:02:03:04:05");
+}
+
+#[tokio::test]
+async fn struct_with_macaddr_field() {
+    #[derive(Debug, Deserialize, PartialEq, Eq)]
+    struct Device {
+        name: String,
+        mac: String,
+    }
+
+    let row: Device =
+        fetch_one("SELECT 'router' AS name, '08-00-2B-01-02-03' :: MACADDR AS mac")
+            .await
+            .unwrap();
+    assert_eq!(
+        row,
+        Device {
+            name: "router".to_owned(),
+            mac: "08:00:2b:01:02:03".to_owned(),
+        }
+    );
+}
@@ -0,0 +1,62 @@
+mod util;
+
+use serde::{de::Error as _, Deserialize};
+use serde_sqlx::{from_pg_row_with_config, DeserializeError, DeserializerConfig, PgTypeDispatch};
+use sqlx::{postgres::types::PgMoney, Decode, Postgres};
+use util::fetch_row;
+
+fn config() -> DeserializerConfig {
+    let mut type_dispatch = PgTypeDispatch::new();
+    // MONEY as an amount of cents
+    type_dispatch.insert("MONEY", |value, visitor| {
+        let money =
+            <PgMoney as Decode<Postgres>>::decode(value).map_err(DeserializeError::custom)?;
+        visitor.visit_i64(money.0)
+    });
+    // Override a built-in type
+    type_dispatch.insert("BOOL", |value, visitor| {
+        let flag = <bool as Decode<Postgres>>::decode(value).map_err(DeserializeError::custom)?;
+        visitor.visit_str(if flag { "yes" } else { "no" })
+    });
+
+    DeserializerConfig {
+        type_dispatch,
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn custom_handler_for_unhandled_type() {
+    let row = fetch_row("SELECT '12.34' :: MONEY AS price").await;
+    let price: i64 = from_pg_row_with_config(row, &config()).unwrap();
+    assert_eq!(price, 1234);
+}
+
+#[tokio::test]
+async fn custom_handler_overrides_builtin() {
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Record {
+        id: i32,
+        active: String,
+        price: Option<i64>,
+    }
+
+    let row = fetch_row("SELECT 1 id, true active, NULL :: MONEY price").await;
+    let record: Record = from_pg_row_with_config(row, &config()).unwrap();
+    assert_eq!(
+        record,
+        Record {
+            id: 1,
+            active: "yes".to_owned(),
+            price: None,
+        }
+    );
+}
+
+#[tokio::test]
+async fn unhandled_binary_type_falls_back_to_hex() {
+    // A TID is sent as 6 bytes in the binary format, which aren't valid UTF-8 here
+    let row = fetch_row("SELECT '(4294967295,1)' :: TID AS tid").await;
+    let tid: String = serde_sqlx::from_pg_row(row).unwrap();
+    assert_eq!(tid, "\\xffffffff0001");
+}
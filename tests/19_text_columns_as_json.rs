@@ -0,0 +1,48 @@
+mod util;
+
+use serde::Deserialize;
+use serde_sqlx::{from_pg_row_with_config, DeserializerConfig};
+use util::fetch_row;
+
+#[derive(Debug, Deserialize, PartialEq, Eq)]
+struct Meta {
+    x: i32,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Eq)]
+struct Record {
+    id: i32,
+    meta: Meta,
+}
+
+#[tokio::test]
+async fn text_column_as_json() {
+    let config = DeserializerConfig {
+        text_columns_as_json: vec!["meta".to_owned()],
+        ..Default::default()
+    };
+
+    let row = fetch_row(r#"SELECT 1 id, '{"x":1}' :: TEXT meta"#).await;
+    let record: Record = from_pg_row_with_config(row, &config).unwrap();
+
+    assert_eq!(
+        record,
+        Record {
+            id: 1,
+            meta: Meta { x: 1 }
+        }
+    );
+}
+
+#[tokio::test]
+async fn text_column_not_listed_stays_a_string() {
+    let config = DeserializerConfig {
+        text_columns_as_json: vec!["other".to_owned()],
+        ..Default::default()
+    };
+
+    let row = fetch_row(r#"SELECT 1 id, '{"x":1}' :: TEXT meta"#).await;
+    let result = from_pg_row_with_config::<Record>(row, &config);
+
+    assert!(result.is_err());
+}
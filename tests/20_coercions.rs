@@ -0,0 +1,71 @@
+mod util;
+
+use serde_sqlx::{
+    from_pg_row_with_config,
+    pg_type_compatibility::{is_compatible, Compatibility},
+    DeserializerConfig,
+};
+use util::fetch_row;
+
+fn strict() -> DeserializerConfig {
+    DeserializerConfig {
+        require_safe_coercions: true,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn compatibility_table() {
+    assert_eq!(is_compatible("INT4", "i64"), Compatibility::Safe);
+    assert_eq!(is_compatible("INT8", "i32"), Compatibility::Lossy);
+    assert_eq!(is_compatible("FLOAT8", "f32"), Compatibility::Lossy);
+    assert_eq!(is_compatible("FLOAT8", "i32"), Compatibility::Incompatible);
+    assert_eq!(is_compatible("UUID", "String"), Compatibility::Safe);
+    assert_eq!(is_compatible("BOOL", "String"), Compatibility::Incompatible);
+    assert_eq!(
+        is_compatible("BYTEA", "String"),
+        Compatibility::Incompatible
+    );
+}
+
+#[tokio::test]
+async fn lossy_coercion_allowed_by_default() {
+    let row = fetch_row("SELECT 4.5 :: FLOAT8 AS value").await;
+    let value: f32 = from_pg_row_with_config(row, &DeserializerConfig::default()).unwrap();
+    assert_eq!(value, 4.5);
+}
+
+#[tokio::test]
+async fn lossy_coercion_rejected_when_strict() {
+    let row = fetch_row("SELECT 4.5 :: FLOAT8 AS value").await;
+    let err = from_pg_row_with_config::<f32>(row, &strict()).unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("lossy coercion from PG type FLOAT8 into f32"));
+}
+
+#[tokio::test]
+async fn safe_coercions_allowed_when_strict() {
+    #[derive(Debug, serde::Deserialize, PartialEq)]
+    struct Record {
+        small: i64,
+        double: f64,
+        label: String,
+        missing: Option<i32>,
+    }
+
+    let row = fetch_row(
+        "SELECT 1 :: INT2 small, 2.5 :: FLOAT4 double, '42' :: TEXT label, NULL :: INT8 missing",
+    )
+    .await;
+    let record: Record = from_pg_row_with_config(row, &strict()).unwrap();
+    assert_eq!(
+        record,
+        Record {
+            small: 1,
+            double: 2.5,
+            label: "42".to_owned(),
+            missing: None,
+        }
+    );
+}
@@ -0,0 +1,228 @@
+mod util;
+
+use serde_sqlx::{from_pg_row_with_config, DeserializerConfig, TimestampFormat};
+use util::{fetch_one, fetch_row};
+
+#[tokio::test]
+async fn time_as_string() {
+    let time: String = fetch_one("SELECT '12:30:00'::TIME").await.unwrap();
+
+    assert_eq!(time, "12:30:00");
+}
+
+#[tokio::test]
+async fn timetz_as_string_with_offset() {
+    let time: String = fetch_one("SELECT '12:30:00-03'::TIMETZ").await.unwrap();
+
+    assert_eq!(time, "12:30:00-03:00");
+}
+
+// The formats below are the contract for crates other than chrono, e.g. `time`
+// parses them with `time::serde::rfc3339` and its ISO 8601 date format
+
+#[tokio::test]
+async fn date_as_iso_8601_string() {
+    let date: String = fetch_one("SELECT '2024-02-29'::DATE").await.unwrap();
+
+    assert_eq!(date, "2024-02-29");
+}
+
+#[tokio::test]
+async fn timestamptz_as_rfc_3339_string() {
+    let ts: String = fetch_one("SELECT '2024-02-29 12:30:00.5+00'::TIMESTAMPTZ")
+        .await
+        .unwrap();
+
+    assert_eq!(ts, "2024-02-29T12:30:00.500+00:00");
+}
+
+#[tokio::test]
+async fn timestamptz_with_offset_is_read_as_utc() {
+    let ts: String = fetch_one("SELECT '2024-01-01 12:00:00+05:30'::TIMESTAMPTZ")
+        .await
+        .unwrap();
+
+    assert_eq!(ts, "2024-01-01T06:30:00+00:00");
+}
+
+#[tokio::test]
+async fn timestamptz_as_chrono_utc() {
+    let ts: chrono::DateTime<chrono::Utc> =
+        fetch_one("SELECT '2024-01-01 12:00:00+05:30'::TIMESTAMPTZ")
+            .await
+            .unwrap();
+
+    assert_eq!(ts, "2024-01-01T06:30:00Z".parse::<chrono::DateTime<chrono::Utc>>().unwrap());
+}
+
+#[tokio::test]
+async fn timestamp_as_string_without_offset() {
+    let ts: String = fetch_one("SELECT '2024-01-31 12:30:00.25'::TIMESTAMP")
+        .await
+        .unwrap();
+
+    assert_eq!(ts, "2024-01-31T12:30:00.250");
+}
+
+#[tokio::test]
+async fn now_as_naive_timestamp() {
+    #[derive(serde::Deserialize)]
+    struct Row {
+        now: String,
+        naive: chrono::NaiveDateTime,
+    }
+
+    let row: Row = fetch_one("SELECT NOW()::TIMESTAMP AS now, NOW()::TIMESTAMP AS naive")
+        .await
+        .unwrap();
+
+    assert!(!row.now.ends_with("+00:00"), "{}", row.now);
+    assert_eq!(row.now.parse::<chrono::NaiveDateTime>().unwrap(), row.naive);
+}
+
+fn epoch_config(timestamp_format: TimestampFormat) -> DeserializerConfig {
+    DeserializerConfig {
+        timestamp_format,
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn timestamptz_as_unix_seconds() {
+    let row = fetch_row("SELECT '2024-01-01 12:00:00+05:30'::TIMESTAMPTZ AS at").await;
+    let at: i64 = from_pg_row_with_config(row, &epoch_config(TimestampFormat::UnixSeconds))
+        .unwrap();
+
+    assert_eq!(at, 1_704_090_600);
+}
+
+#[tokio::test]
+async fn timestamptz_as_unix_millis() {
+    let row = fetch_row("SELECT '2024-01-01 06:30:00.123+00'::TIMESTAMPTZ AS at").await;
+    let at: i64 =
+        from_pg_row_with_config(row, &epoch_config(TimestampFormat::UnixMillis)).unwrap();
+
+    assert_eq!(at, 1_704_090_600_123);
+}
+
+#[tokio::test]
+async fn struct_with_epoch_timestamps() {
+    #[derive(Debug, serde::Deserialize, PartialEq, Eq)]
+    struct Event {
+        id: i32,
+        created_at: i64,
+        deleted_at: Option<i64>,
+    }
+
+    let row = fetch_row(
+        "SELECT 1 AS id, 'epoch'::TIMESTAMPTZ AS created_at, NULL::TIMESTAMPTZ AS deleted_at",
+    )
+    .await;
+    let config = DeserializerConfig {
+        require_safe_coercions: true,
+        ..epoch_config(TimestampFormat::UnixSeconds)
+    };
+    let event: Event = from_pg_row_with_config(row, &config).unwrap();
+
+    assert_eq!(
+        event,
+        Event {
+            id: 1,
+            created_at: 0,
+            deleted_at: None
+        }
+    );
+}
+
+#[tokio::test]
+async fn interval_as_string() {
+    let interval: String = fetch_one("SELECT INTERVAL '2 days 3 hours'").await.unwrap();
+
+    assert_eq!(interval, "PT183600S");
+}
+
+#[tokio::test]
+async fn interval_as_i64_micros() {
+    let micros: i64 = fetch_one("SELECT INTERVAL '2 days 3 hours'").await.unwrap();
+
+    assert_eq!(micros, 183_600_000_000);
+}
+
+#[tokio::test]
+async fn interval_months_count_as_30_days() {
+    let micros: u64 = fetch_one("SELECT INTERVAL '1 month 1 second'").await.unwrap();
+
+    assert_eq!(micros, 2_592_001_000_000);
+}
+
+#[tokio::test]
+async fn interval_as_std_duration() {
+    let duration: std::time::Duration = fetch_one("SELECT INTERVAL '1.5 seconds'")
+        .await
+        .unwrap();
+
+    assert_eq!(duration, std::time::Duration::from_millis(1500));
+}
+
+#[tokio::test]
+async fn struct_with_duration_fields() {
+    #[derive(Debug, serde::Deserialize, PartialEq, Eq)]
+    struct Job {
+        timeout: std::time::Duration,
+        retry_after: Option<std::time::Duration>,
+    }
+
+    let job: Job = fetch_one(
+        "SELECT INTERVAL '2 days 3 hours' AS timeout, NULL::INTERVAL AS retry_after",
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        job,
+        Job {
+            timeout: std::time::Duration::from_secs(183_600),
+            retry_after: None
+        }
+    );
+}
+
+#[tokio::test]
+async fn negative_interval_as_std_duration_fails() {
+    let err = fetch_one::<std::time::Duration>("SELECT INTERVAL '-1 second'")
+        .await
+        .unwrap_err();
+
+    assert!(err.to_string().contains("negative INTERVAL"), "{err}");
+}
+
+#[tokio::test]
+async fn interval_string_counts_months_as_30_days() {
+    let interval: String = fetch_one("SELECT INTERVAL '1 month 1 second'").await.unwrap();
+
+    assert_eq!(interval, "PT2592001S");
+
+    let intervals: Vec<String> = fetch_one("SELECT ARRAY[INTERVAL '1 month']").await.unwrap();
+
+    assert_eq!(intervals, ["PT2592000S"]);
+}
+
+#[tokio::test]
+async fn any_struct_named_duration_reads_an_interval() {
+    // Only the name and fields are known, so this is read like `std::time::Duration`
+    #[derive(Debug, serde::Deserialize, PartialEq, Eq)]
+    struct Duration {
+        secs: u64,
+        nanos: u32,
+    }
+
+    let duration: Duration = fetch_one("SELECT INTERVAL '1.5 seconds'").await.unwrap();
+
+    assert_eq!(
+        duration,
+        Duration {
+            secs: 1,
+            nanos: 500_000_000
+        }
+    );
+}
@@ -0,0 +1,111 @@
+mod util;
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use serde_sqlx::{from_pg_row_ref, update_from_pg_row};
+use sqlx::Row;
+use util::fetch_row;
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct User {
+    id: i32,
+    name: String,
+}
+
+#[tokio::test]
+async fn row_is_usable_after_deserializing() {
+    let row = fetch_row("SELECT 1 AS id, 'alice' AS name").await;
+
+    let user: User = from_pg_row_ref(&row).unwrap();
+    assert_eq!(
+        user,
+        User {
+            id: 1,
+            name: "alice".to_owned()
+        }
+    );
+
+    let name: String = row.try_get("name").unwrap();
+    assert_eq!(name, "alice");
+}
+
+#[tokio::test]
+async fn same_row_into_two_types() {
+    let row = fetch_row("SELECT 'x' AS a, 'y' AS b").await;
+
+    let map: HashMap<String, String> = from_pg_row_ref(&row).unwrap();
+    let tuple: (String, String) = from_pg_row_ref(&row).unwrap();
+
+    assert_eq!(map["a"], tuple.0);
+    assert_eq!(map["b"], tuple.1);
+}
+
+#[tokio::test]
+async fn rows_into_vec() {
+    let rows = util::fetch_rows("SELECT 1 AS id, 'alice' AS name UNION ALL SELECT 2, 'bob'").await;
+
+    let borrowed: Vec<User> = serde_sqlx::from_pg_rows_ref(&rows).unwrap();
+    let owned: Vec<User> = serde_sqlx::from_pg_rows(rows).unwrap();
+
+    assert_eq!(borrowed, owned);
+    assert_eq!(
+        owned,
+        vec![
+            User {
+                id: 1,
+                name: "alice".to_owned()
+            },
+            User {
+                id: 2,
+                name: "bob".to_owned()
+            }
+        ]
+    );
+}
+
+#[tokio::test]
+async fn rows_into_vec_stops_at_first_error() {
+    let rows = util::fetch_rows("SELECT 1 AS id, 'alice' AS name UNION ALL SELECT 2, NULL").await;
+
+    assert!(serde_sqlx::from_pg_rows_ref::<User>(&rows).is_err());
+    assert!(serde_sqlx::from_pg_rows::<User>(rows).is_err());
+}
+
+#[tokio::test]
+async fn no_rows_into_empty_vec() {
+    let users: Vec<User> = serde_sqlx::from_pg_rows(vec![]).unwrap();
+
+    assert!(users.is_empty());
+}
+
+#[tokio::test]
+async fn update_replaces_the_value() {
+    let mut user = User {
+        id: 1,
+        name: "alice".to_owned(),
+    };
+
+    let row = fetch_row("SELECT 1 AS id, 'bob' AS name").await;
+    update_from_pg_row(row, &mut user).unwrap();
+    assert_eq!(
+        user,
+        User {
+            id: 1,
+            name: "bob".to_owned()
+        }
+    );
+}
+
+#[tokio::test]
+async fn failed_update_leaves_the_value() {
+    let mut user = User {
+        id: 1,
+        name: "alice".to_owned(),
+    };
+
+    let row = fetch_row("SELECT 2 AS id").await;
+    update_from_pg_row(row, &mut user).unwrap_err();
+    assert_eq!(user.id, 1);
+    assert_eq!(user.name, "alice");
+}
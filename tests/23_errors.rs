@@ -0,0 +1,82 @@
+mod util;
+
+use serde::{de::Error as _, Deserialize};
+use serde_sqlx::{from_pg_row, from_pg_row_with_config, DeserializeError, DeserializerConfig};
+use util::fetch_row;
+
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+struct Record {
+    id: i32,
+    total: f64,
+}
+
+#[tokio::test]
+async fn null_into_non_optional_field() {
+    let row = fetch_row("SELECT 1 AS id, NULL :: FLOAT8 AS total").await;
+
+    let err = from_pg_row::<Record>(row).unwrap_err();
+    assert!(
+        matches!(&err, DeserializeError::NullValue { column } if column == "total"),
+        "{err:?}"
+    );
+}
+
+#[tokio::test]
+async fn incompatible_type_when_strict() {
+    let row = fetch_row("SELECT true AS id, 1.5 :: FLOAT8 AS total").await;
+    let config = DeserializerConfig {
+        require_safe_coercions: true,
+        ..Default::default()
+    };
+
+    let err = from_pg_row_with_config::<Record>(row, &config).unwrap_err();
+    assert!(
+        matches!(
+            &err,
+            DeserializeError::TypeMismatch { column, pg_type, target_type: "i32" }
+                if column == "id" && pg_type == "BOOL"
+        ),
+        "{err:?}"
+    );
+}
+
+#[tokio::test]
+async fn decode_failure_keeps_the_source() {
+    // Neither rust_decimal nor bigdecimal has a representation for NaN
+    let row = fetch_row("SELECT 1 AS id, 'NaN' :: NUMERIC AS total").await;
+
+    let err = from_pg_row::<Record>(row).unwrap_err();
+    assert!(
+        matches!(&err, DeserializeError::DecodeFailure { column, .. } if column == "total"),
+        "{err:?}"
+    );
+    assert!(std::error::Error::source(&err).is_some());
+}
+
+#[test]
+fn custom_errors_and_interop() {
+    let err = DeserializeError::custom("something went wrong");
+    assert_eq!(err.to_string(), "something went wrong");
+
+    let err: DeserializeError = serde::de::value::Error::custom("from serde").into();
+    assert!(matches!(err, DeserializeError::Custom(message) if message == "from serde"));
+}
+
+#[tokio::test]
+async fn messages_name_the_column_and_pg_type() {
+    #[derive(Debug, Deserialize)]
+    #[allow(dead_code)]
+    struct Stats {
+        calls: i64,
+        total_exec_time: String,
+    }
+
+    let row = fetch_row("SELECT 3 :: INT8 AS calls, 1.5 :: FLOAT8 AS total_exec_time").await;
+
+    let err = from_pg_row::<Stats>(row).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "failed to deserialize column 'total_exec_time' (PG type FLOAT8): invalid type: floating point `1.5`, expected a string"
+    );
+}
@@ -0,0 +1,144 @@
+mod util;
+
+use serde::Deserialize;
+use serde_sqlx::DeserializerConfig;
+use tokio::sync::OnceCell;
+use util::{execute, fetch_all, fetch_one, fetch_row};
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+enum Status {
+    Active,
+    Inactive,
+    #[serde(rename = "on-hold")]
+    OnHold,
+}
+
+#[tokio::test]
+async fn text_column_as_unit_enum() {
+    let rows: Vec<Status> =
+        fetch_all("SELECT 'active' UNION ALL SELECT 'inactive' UNION ALL SELECT 'on-hold'")
+            .await
+            .unwrap();
+
+    assert_eq!(rows, [Status::Active, Status::Inactive, Status::OnHold]);
+}
+
+#[tokio::test]
+async fn varchar_column_as_optional_enum() {
+    let rows: Vec<Option<Status>> =
+        fetch_all("SELECT 'active'::VARCHAR UNION ALL SELECT NULL::VARCHAR")
+            .await
+            .unwrap();
+
+    assert_eq!(rows, [Some(Status::Active), None]);
+}
+
+#[tokio::test]
+async fn enum_as_struct_field() {
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct User {
+        id: i32,
+        status: Status,
+    }
+
+    let user: User = fetch_one("SELECT 1 AS id, 'inactive' AS status")
+        .await
+        .unwrap();
+
+    assert_eq!(
+        user,
+        User {
+            id: 1,
+            status: Status::Inactive
+        }
+    );
+}
+
+#[tokio::test]
+async fn unknown_variant_fails() {
+    let err = fetch_one::<Status>("SELECT 'deleted' AS status")
+        .await
+        .unwrap_err();
+
+    assert!(
+        err.to_string().contains("unknown variant `deleted`"),
+        "{err}"
+    );
+}
+
+/// Tests in this file run concurrently, and racing `CREATE TYPE`s can fail
+async fn create_status_type() {
+    static CREATED: OnceCell<()> = OnceCell::const_new();
+
+    CREATED
+        .get_or_init(|| {
+            execute(
+                "DO $$ BEGIN
+                    CREATE TYPE serde_sqlx_status AS ENUM ('active', 'inactive', 'on-hold');
+                EXCEPTION WHEN duplicate_object THEN NULL;
+                END $$",
+            )
+        })
+        .await;
+}
+
+#[tokio::test]
+async fn postgres_enum_as_unit_enum() {
+    create_status_type().await;
+
+    let rows: Vec<Option<Status>> = fetch_all(
+        "SELECT 'on-hold'::serde_sqlx_status UNION ALL SELECT 'active' UNION ALL SELECT NULL",
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(rows, [Some(Status::OnHold), Some(Status::Active), None]);
+}
+
+#[tokio::test]
+async fn postgres_enum_as_string_field() {
+    create_status_type().await;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct User {
+        id: i32,
+        status: Status,
+        label: String,
+    }
+
+    let row = fetch_row(
+        "SELECT 1 AS id, 'inactive'::serde_sqlx_status AS status, \
+                'active'::serde_sqlx_status AS label",
+    )
+    .await;
+    let config = DeserializerConfig {
+        require_safe_coercions: true,
+        ..Default::default()
+    };
+
+    assert_eq!(
+        serde_sqlx::from_pg_row_with_config::<User>(row, &config).unwrap(),
+        User {
+            id: 1,
+            status: Status::Inactive,
+            label: "active".to_owned()
+        }
+    );
+}
+
+#[tokio::test]
+async fn postgres_enum_label_without_a_variant() {
+    create_status_type().await;
+
+    #[derive(Debug, Deserialize)]
+    enum Binary {
+        #[serde(rename = "active")]
+        _Active,
+    }
+
+    let err = fetch_one::<Binary>("SELECT 'on-hold'::serde_sqlx_status")
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("unknown variant `on-hold`"), "{err}");
+}
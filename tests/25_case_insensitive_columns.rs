@@ -0,0 +1,42 @@
+mod util;
+
+use serde::Deserialize;
+use serde_sqlx::{from_pg_row, from_pg_row_ci};
+use util::fetch_row;
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct User {
+    userid: i32,
+    #[serde(rename = "UserName")]
+    user_name: String,
+}
+
+#[tokio::test]
+async fn quoted_mixed_case_columns_match_fields() {
+    let row = fetch_row(r#"SELECT 7 AS "UserID", 'alice' AS "USERNAME""#).await;
+
+    let user: User = from_pg_row_ci(row).unwrap();
+    assert_eq!(
+        user,
+        User {
+            userid: 7,
+            user_name: "alice".to_owned()
+        }
+    );
+}
+
+#[tokio::test]
+async fn folded_column_matches_renamed_field() {
+    // Unquoted identifiers are folded to lowercase by Postgres
+    let row = fetch_row("SELECT 7 AS UserID, 'alice' AS UserName").await;
+
+    let user: User = from_pg_row_ci(row).unwrap();
+    assert_eq!(user.user_name, "alice");
+}
+
+#[tokio::test]
+async fn matching_is_case_sensitive_by_default() {
+    let row = fetch_row(r#"SELECT 7 AS "UserID", 'alice' AS "UserName""#).await;
+
+    assert!(from_pg_row::<User>(row).is_err());
+}
@@ -0,0 +1,77 @@
+mod util;
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use tokio::sync::OnceCell;
+use util::{execute, fetch_one};
+
+/// Tests in this file run concurrently, and racing `CREATE EXTENSION`s can fail
+async fn create_hstore_extension() {
+    static CREATED: OnceCell<()> = OnceCell::const_new();
+
+    CREATED
+        .get_or_init(|| execute("CREATE EXTENSION IF NOT EXISTS hstore"))
+        .await;
+}
+
+#[tokio::test]
+async fn hstore_as_hashmap() {
+    create_hstore_extension().await;
+
+    let row: HashMap<String, Option<String>> =
+        fetch_one("SELECT 'a=>1, b=>NULL'::hstore AS value")
+            .await
+            .unwrap();
+
+    assert_eq!(
+        row,
+        HashMap::from([("a".to_owned(), Some("1".to_owned())), ("b".to_owned(), None)])
+    );
+}
+
+#[tokio::test]
+async fn hstore_as_struct() {
+    create_hstore_extension().await;
+
+    #[derive(Debug, Deserialize, PartialEq, Eq)]
+    struct Attributes {
+        a: String,
+        b: Option<String>,
+    }
+
+    let row: Attributes = fetch_one("SELECT 'a=>1, b=>NULL'::hstore AS value")
+        .await
+        .unwrap();
+
+    assert_eq!(
+        row,
+        Attributes {
+            a: "1".to_owned(),
+            b: None,
+        }
+    );
+}
+
+#[tokio::test]
+async fn hstore_as_struct_field() {
+    create_hstore_extension().await;
+
+    #[derive(Debug, Deserialize, PartialEq, Eq)]
+    struct Record {
+        id: i32,
+        attributes: HashMap<String, Option<String>>,
+    }
+
+    let row: Record = fetch_one("SELECT 1 AS id, 'color=>red'::hstore AS attributes")
+        .await
+        .unwrap();
+
+    assert_eq!(
+        row,
+        Record {
+            id: 1,
+            attributes: HashMap::from([("color".to_owned(), Some("red".to_owned()))]),
+        }
+    );
+}
@@ -0,0 +1,51 @@
+mod util;
+
+use serde::Deserialize;
+use serde_sqlx::{from_pg_row_with_config, DeserializerConfig};
+use util::{fetch_one, fetch_row};
+
+#[tokio::test]
+async fn money_as_cents() {
+    let row: i64 = fetch_one("SELECT 12.34 :: MONEY AS price").await.unwrap();
+    assert_eq!(row, 1234);
+}
+
+#[tokio::test]
+async fn negative_money_as_cents() {
+    let row: Option<i64> = fetch_one("SELECT '-0.50' :: MONEY AS price").await.unwrap();
+    assert_eq!(row, Some(-50));
+}
+
+#[tokio::test]
+async fn money_as_dollars() {
+    let config = DeserializerConfig {
+        money_as_dollars: true,
+        ..Default::default()
+    };
+
+    let row = fetch_row("SELECT 12.34 :: MONEY AS price").await;
+    let price: f64 = from_pg_row_with_config(row, &config).unwrap();
+
+    assert_eq!(price, 12.34);
+}
+
+#[tokio::test]
+async fn struct_with_money_fields() {
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Order {
+        total: i64,
+        discount: Option<i64>,
+    }
+
+    let row: Order = fetch_one("SELECT 99.99 :: MONEY AS total, NULL :: MONEY AS discount")
+        .await
+        .unwrap();
+
+    assert_eq!(
+        row,
+        Order {
+            total: 9999,
+            discount: None
+        }
+    );
+}
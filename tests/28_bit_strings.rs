@@ -0,0 +1,63 @@
+mod util;
+
+use serde::Deserialize;
+use util::fetch_one;
+
+#[tokio::test]
+async fn bit_as_string() {
+    let row: String = fetch_one("SELECT '1010' :: BIT(4) AS flags").await.unwrap();
+    assert_eq!(row, "1010");
+}
+
+#[tokio::test]
+async fn bit_as_vec_of_bool() {
+    let row: Vec<bool> = fetch_one("SELECT '1010' :: BIT(4) AS flags").await.unwrap();
+    assert_eq!(row, vec![true, false, true, false]);
+}
+
+#[tokio::test]
+async fn varbit_spanning_several_bytes_as_string() {
+    let row: String = fetch_one("SELECT '1100110011' :: VARBIT AS flags")
+        .await
+        .unwrap();
+    assert_eq!(row, "1100110011");
+}
+
+#[tokio::test]
+async fn empty_varbit_as_vec_of_bool() {
+    let row: Vec<bool> = fetch_one("SELECT '' :: VARBIT AS flags").await.unwrap();
+    assert!(row.is_empty());
+}
+
+#[tokio::test]
+async fn struct_with_bit_fields() {
+    #[derive(Debug, Deserialize, PartialEq, Eq)]
+    struct Permissions {
+        mask: String,
+        bits: Vec<bool>,
+        extra: Option<Vec<bool>>,
+    }
+
+    let row: Permissions = fetch_one(
+        "SELECT '101' :: BIT(3) AS mask, '011' :: VARBIT AS bits, NULL :: VARBIT AS extra",
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        row,
+        Permissions {
+            mask: "101".to_owned(),
+            bits: vec![false, true, true],
+            extra: None,
+        }
+    );
+}
+
+#[tokio::test]
+async fn varbit_array_as_vec_of_strings() {
+    let row: Vec<String> = fetch_one("SELECT ARRAY['1', '01'] :: VARBIT[] AS flags")
+        .await
+        .unwrap();
+    assert_eq!(row, vec!["1", "01"]);
+}
@@ -0,0 +1,68 @@
+#![cfg(feature = "rust_decimal")]
+
+mod util;
+
+use std::str::FromStr;
+
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use util::fetch_one;
+
+#[tokio::test]
+async fn numeric_as_decimal_keeps_precision() {
+    let row: Decimal = fetch_one("SELECT 1234567890.123456789012345 :: NUMERIC AS value")
+        .await
+        .unwrap();
+    assert_eq!(row, Decimal::from_str("1234567890.123456789012345").unwrap());
+}
+
+#[tokio::test]
+async fn numeric_as_string() {
+    let row: String = fetch_one("SELECT 0.1 :: NUMERIC AS value").await.unwrap();
+    assert_eq!(row, "0.1");
+}
+
+#[tokio::test]
+async fn numeric_as_f64() {
+    let row: f64 = fetch_one("SELECT 2.5 :: NUMERIC AS value").await.unwrap();
+    assert_eq!(row, 2.5);
+}
+
+#[tokio::test]
+async fn whole_numeric_as_i64() {
+    let row: i64 = fetch_one("SELECT 9007199254740993 :: NUMERIC AS value")
+        .await
+        .unwrap();
+    assert_eq!(row, 9_007_199_254_740_993);
+}
+
+#[tokio::test]
+async fn fractional_numeric_as_i64_fails() {
+    let err = fetch_one::<i64>("SELECT 1.5 :: NUMERIC AS value")
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("1.5 does not fit into i64"), "{err}");
+}
+
+#[tokio::test]
+async fn struct_with_decimal_fields() {
+    #[derive(Debug, Deserialize, PartialEq, Eq)]
+    struct Invoice {
+        total: Decimal,
+        tax: Option<Decimal>,
+    }
+
+    let row: Invoice = fetch_one(
+        "SELECT 99999999999999.99 :: NUMERIC AS total, NULL :: NUMERIC AS tax",
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        row,
+        Invoice {
+            total: Decimal::from_str("99999999999999.99").unwrap(),
+            tax: None,
+        }
+    );
+}
@@ -0,0 +1,85 @@
+#![cfg(feature = "bigdecimal")]
+
+mod util;
+
+use serde::Deserialize;
+use util::fetch_one;
+
+#[tokio::test]
+async fn numeric_beyond_f64_precision_as_exact_string() {
+    let row: String = fetch_one("SELECT 1234567890.12345678901234567890 :: NUMERIC AS value")
+        .await
+        .unwrap();
+    assert_eq!(row, "1234567890.12345678901234567890");
+}
+
+#[tokio::test]
+async fn numeric_text_keeps_scale_and_sign() {
+    let rows: Vec<String> = util::fetch_all(
+        "SELECT value :: TEXT::NUMERIC AS value FROM (VALUES
+            ('0'), ('5.00'), ('-0.0001'), ('100000000'), ('-98765432109876543210.5')
+        ) AS t(value)",
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        rows,
+        vec![
+            "0",
+            "5.00",
+            "-0.0001",
+            "100000000",
+            "-98765432109876543210.5"
+        ]
+    );
+}
+
+#[tokio::test]
+async fn numeric_as_f64_and_i64() {
+    let float: f64 = fetch_one("SELECT 2.5 :: NUMERIC AS value").await.unwrap();
+    assert_eq!(float, 2.5);
+
+    let int: i64 = fetch_one("SELECT 42.000 :: NUMERIC AS value").await.unwrap();
+    assert_eq!(int, 42);
+}
+
+#[tokio::test]
+async fn struct_with_numeric_fields() {
+    #[derive(Debug, Deserialize, PartialEq, Eq)]
+    struct Balance {
+        amount: String,
+        pending: Option<String>,
+    }
+
+    let row: Balance = fetch_one(
+        "SELECT 123456789012345678901234567890.1 :: NUMERIC AS amount, NULL :: NUMERIC AS pending",
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        row,
+        Balance {
+            amount: "123456789012345678901234567890.1".to_owned(),
+            pending: None,
+        }
+    );
+}
+
+#[tokio::test]
+async fn numeric_array_as_exact_strings() {
+    let row: Vec<Option<String>> =
+        fetch_one("SELECT ARRAY[0.1, NULL, 12345678901234567890123.45] :: NUMERIC[] AS value")
+            .await
+            .unwrap();
+
+    assert_eq!(
+        row,
+        vec![
+            Some("0.1".to_owned()),
+            None,
+            Some("12345678901234567890123.45".to_owned())
+        ]
+    );
+}
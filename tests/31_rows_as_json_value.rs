@@ -0,0 +1,194 @@
+mod util;
+
+use std::collections::HashMap;
+
+use serde_json::{json, Value};
+use util::{fetch_all, fetch_one};
+
+#[tokio::test]
+async fn mixed_row_as_json_object() {
+    let row: Value = fetch_one("SELECT 1 a, 'hello' b, true c, NULL d")
+        .await
+        .unwrap();
+
+    assert_eq!(row, json!({ "a": 1, "b": "hello", "c": true, "d": null }));
+}
+
+#[tokio::test]
+async fn arrays_and_json_columns_as_json_values() {
+    let row: Value = fetch_one(
+        r#"SELECT ARRAY[1, NULL, 3] AS ints, '{"x": [1, 2]}' :: JSONB AS doc, 1.5 :: FLOAT8 AS f"#,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        row,
+        json!({ "ints": [1, null, 3], "doc": { "x": [1, 2] }, "f": 1.5 })
+    );
+}
+
+#[tokio::test]
+async fn single_scalar_column_stays_a_scalar() {
+    let row: Value = fetch_one("SELECT 42 AS answer").await.unwrap();
+
+    assert_eq!(row, json!(42));
+}
+
+#[tokio::test]
+async fn many_rows_as_json_objects() {
+    let rows: Vec<Value> = fetch_all("SELECT 1 id, 'a' name UNION ALL SELECT 2, 'b'")
+        .await
+        .unwrap();
+
+    assert_eq!(
+        rows,
+        vec![
+            json!({ "id": 1, "name": "a" }),
+            json!({ "id": 2, "name": "b" })
+        ]
+    );
+}
+
+#[tokio::test]
+async fn mixed_row_as_hashmap_of_json_values() {
+    let row: HashMap<String, Value> = fetch_one(
+        r#"SELECT 1 AS id, 'alice' AS name, NULL::TEXT AS bio, 2.5::FLOAT8 AS score,
+                  ARRAY['a', 'b'] AS tags, '{"admin": true}'::JSONB AS flags,
+                  '2024-01-01'::DATE AS joined"#,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        row,
+        HashMap::from([
+            ("id".to_owned(), json!(1)),
+            ("name".to_owned(), json!("alice")),
+            ("bio".to_owned(), Value::Null),
+            ("score".to_owned(), json!(2.5)),
+            ("tags".to_owned(), json!(["a", "b"])),
+            ("flags".to_owned(), json!({ "admin": true })),
+            ("joined".to_owned(), json!("2024-01-01")),
+        ])
+    );
+}
+
+#[tokio::test]
+async fn single_column_as_hashmap_of_json_values() {
+    let row: HashMap<String, Value> = fetch_one("SELECT 42 AS answer").await.unwrap();
+
+    assert_eq!(row, HashMap::from([("answer".to_owned(), json!(42))]));
+}
+
+#[tokio::test]
+async fn row_to_json_object() {
+    let row = util::fetch_row(
+        r#"SELECT 1 AS id, 'alice' AS name, NULL::TEXT AS bio, ARRAY[1, 2] AS ids,
+                  '{"admin": true}'::JSONB AS flags"#,
+    )
+    .await;
+
+    assert_eq!(
+        serde_sqlx::row_to_json(&row).unwrap(),
+        json!({ "id": 1, "name": "alice", "bio": null, "ids": [1, 2], "flags": { "admin": true } })
+    );
+}
+
+#[tokio::test]
+async fn row_to_json_single_column_is_an_object() {
+    let row = util::fetch_row("SELECT 42 AS answer").await;
+
+    assert_eq!(
+        serde_sqlx::row_to_json(&row).unwrap(),
+        json!({ "answer": 42 })
+    );
+}
+
+#[tokio::test]
+async fn row_to_json_keeps_lone_range_column() {
+    let row = util::fetch_row("SELECT '[1, 5)'::INT4RANGE AS r").await;
+
+    let value = serde_sqlx::row_to_json(&row).unwrap();
+    assert_eq!(value["r"]["lower"], json!(1));
+    assert_eq!(value["r"]["upper"], json!(5));
+}
+
+#[tokio::test]
+async fn rows_to_json_array() {
+    let rows = util::fetch_rows(
+        "SELECT 1 AS id, 'a' AS name, true AS active UNION ALL SELECT 2, 'b', NULL",
+    )
+    .await;
+
+    assert_eq!(
+        serde_sqlx::rows_to_json(rows).unwrap(),
+        json!([
+            { "id": 1, "name": "a", "active": true },
+            { "id": 2, "name": "b", "active": null },
+        ])
+    );
+}
+
+#[tokio::test]
+async fn no_rows_to_empty_json_array() {
+    assert_eq!(serde_sqlx::rows_to_json(vec![]).unwrap(), json!([]));
+}
+
+// With `time`, dates and times are in the `time` crate's formats
+#[cfg(not(feature = "time"))]
+#[tokio::test]
+async fn scalar_types_as_json_map() {
+    let row: serde_json::Map<String, Value> = fetch_one(
+        r#"SELECT 1::INT2 AS i2, 2::INT4 AS i4, 3::INT8 AS i8, 1.5::FLOAT4 AS f4,
+                  2.5::FLOAT8 AS f8, true AS b, 42::OID AS oid,
+                  'text'::TEXT AS t, 'vc'::VARCHAR AS vc, 'bp'::CHAR(3) AS bp,
+                  'nm'::NAME AS nm, 'x'::"char" AS ch,
+                  'a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11'::UUID AS id,
+                  '2024-01-02'::DATE AS d, '03:04:05'::TIME AS tm,
+                  '2024-01-02 03:04:05'::TIMESTAMP AS ts,
+                  '2024-01-02 03:04:05+00'::TIMESTAMPTZ AS tstz,
+                  '10.0.0.1'::INET AS ip, '08:00:2b:01:02:03'::MACADDR AS mac,
+                  '\x0102'::BYTEA AS bytes, B'101' AS bits, NULL::INT4 AS nothing"#,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        Value::Object(row),
+        json!({
+            "i2": 1, "i4": 2, "i8": 3, "f4": 1.5, "f8": 2.5, "b": true, "oid": 42,
+            "t": "text", "vc": "vc", "bp": "bp ", "nm": "nm", "ch": "x",
+            "id": "a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11",
+            "d": "2024-01-02", "tm": "03:04:05", "ts": "2024-01-02T03:04:05",
+            "tstz": "2024-01-02T03:04:05+00:00",
+            "ip": "10.0.0.1", "mac": "08:00:2b:01:02:03",
+            "bytes": [1, 2], "bits": "101", "nothing": null,
+        })
+    );
+}
+
+#[tokio::test]
+async fn json_map_single_column_and_nested_values() {
+    let row: serde_json::Map<String, Value> = fetch_one("SELECT 42 AS answer").await.unwrap();
+    assert_eq!(row["answer"], json!(42));
+
+    let row: serde_json::Map<String, Value> = fetch_one(
+        r#"SELECT ARRAY['a', NULL] AS tags, '{"x": 1}'::JSONB AS doc, ARRAY['\x01'::BYTEA] AS blobs"#,
+    )
+    .await
+    .unwrap();
+    assert_eq!(row["tags"], json!(["a", null]));
+    assert_eq!(row["doc"], json!({ "x": 1 }));
+    assert_eq!(row["blobs"], json!([[1]]));
+}
+
+#[tokio::test]
+async fn bytea_into_json_value() {
+    let row = util::fetch_row("SELECT '\\xff00'::BYTEA AS data").await;
+
+    assert_eq!(
+        serde_sqlx::row_to_json(&row).unwrap(),
+        json!({ "data": [255, 0] })
+    );
+}
@@ -0,0 +1,56 @@
+#![cfg(feature = "stream")]
+
+mod util;
+
+use futures_util::TryStreamExt;
+use serde::Deserialize;
+use serde_sqlx::stream::deserialize_stream;
+
+#[derive(Debug, Deserialize, PartialEq, Eq)]
+struct Row {
+    id: i32,
+    name: String,
+}
+
+#[tokio::test]
+async fn fetched_rows_are_deserialized_as_they_arrive() {
+    let conn = util::conn().await;
+
+    let rows: Vec<Row> = deserialize_stream(
+        sqlx::query("SELECT i AS id, 'row ' || i AS name FROM generate_series(1, 3) i")
+            .fetch(&conn),
+    )
+    .try_collect()
+    .await
+    .unwrap();
+
+    assert_eq!(
+        rows,
+        vec![
+            Row {
+                id: 1,
+                name: "row 1".to_owned()
+            },
+            Row {
+                id: 2,
+                name: "row 2".to_owned()
+            },
+            Row {
+                id: 3,
+                name: "row 3".to_owned()
+            },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn query_errors_are_passed_on() {
+    let conn = util::conn().await;
+
+    let err = deserialize_stream::<Row, _>(sqlx::query("SELECT * FROM missing_table").fetch(&conn))
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap_err();
+
+    assert!(err.to_string().contains("missing_table"), "{err}");
+}
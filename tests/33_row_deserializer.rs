@@ -0,0 +1,160 @@
+mod util;
+
+use std::collections::HashMap;
+
+use serde::de::{Deserializer, SeqAccess, Visitor};
+use serde::Deserialize;
+use serde_sqlx::{DeserializerConfig, PgRowDeserializer};
+use util::fetch_row;
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct User {
+    id: i32,
+    name: String,
+}
+
+#[tokio::test]
+async fn offset_skips_leading_columns() {
+    let row = fetch_row("SELECT 3 AS total, 1 AS id, 'alice' AS name").await;
+
+    let user = User::deserialize(
+        PgRowDeserializer::builder()
+            .with_column_offset(1)
+            .build(&row),
+    )
+    .unwrap();
+    assert_eq!(
+        user,
+        User {
+            id: 1,
+            name: "alice".to_owned()
+        }
+    );
+
+    let tuple = <(i32, String)>::deserialize(
+        PgRowDeserializer::builder()
+            .with_column_offset(1)
+            .build(&row),
+    )
+    .unwrap();
+    assert_eq!(tuple, (1, "alice".to_owned()));
+}
+
+#[tokio::test]
+async fn offset_leaves_a_single_column() {
+    let row = fetch_row("SELECT 1 AS id, ARRAY['a', 'b'] AS tags").await;
+
+    let tags = Vec::<String>::deserialize(
+        PgRowDeserializer::builder()
+            .with_column_offset(1)
+            .build(&row),
+    )
+    .unwrap();
+    assert_eq!(tags, ["a", "b"]);
+
+    let map = HashMap::<String, Vec<String>>::deserialize(
+        PgRowDeserializer::builder()
+            .with_column_offset(1)
+            .build(&row),
+    )
+    .unwrap();
+    assert_eq!(map["tags"], ["a", "b"]);
+}
+
+#[tokio::test]
+async fn builder_takes_a_config() {
+    let row = fetch_row(r#"SELECT 1 AS "ID", 'alice' AS "Name""#).await;
+    let config = DeserializerConfig {
+        case_insensitive_columns: true,
+        ..Default::default()
+    };
+
+    let user = User::deserialize(
+        PgRowDeserializer::builder()
+            .with_config(&config)
+            .build(&row),
+    )
+    .unwrap();
+    assert_eq!(user.name, "alice");
+}
+
+/// Sum of every column, through a hand-written visitor
+#[derive(Debug, PartialEq)]
+struct Sum(i64);
+
+impl<'de> Deserialize<'de> for Sum {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct SumVisitor;
+
+        impl<'de> Visitor<'de> for SumVisitor {
+            type Value = Sum;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("integer columns")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Sum, A::Error> {
+                let mut sum = 0;
+                while let Some(value) = seq.next_element::<i64>()? {
+                    sum += value;
+                }
+                Ok(Sum(sum))
+            }
+        }
+
+        deserializer.deserialize_seq(SumVisitor)
+    }
+}
+
+#[tokio::test]
+async fn custom_visitor() {
+    let row = fetch_row("SELECT 100::INT8 AS a, 2::INT8 AS b, 3::INT8 AS c").await;
+
+    assert_eq!(
+        Sum::deserialize(PgRowDeserializer::new(&row)).unwrap(),
+        Sum(105)
+    );
+    assert_eq!(
+        Sum::deserialize(
+            PgRowDeserializer::builder()
+                .with_column_offset(1)
+                .build(&row)
+        )
+        .unwrap(),
+        Sum(5)
+    );
+}
+
+#[tokio::test]
+async fn debug_shows_position_not_values() {
+    let row = fetch_row("SELECT 1 AS id, 'secret' AS name").await;
+    let deserializer = PgRowDeserializer::builder()
+        .with_column_offset(1)
+        .build(&row);
+
+    let debug = format!("{deserializer:?}");
+    assert_eq!(debug, "PgRowDeserializer { columns: 2, index: 1, .. }");
+    assert!(!format!("{:?}", PgRowDeserializer::builder()).is_empty());
+}
+
+#[tokio::test]
+async fn position_accessors() {
+    let row = fetch_row("SELECT 1 AS id, 'alice' AS name").await;
+
+    let deserializer = PgRowDeserializer::new(&row);
+    assert_eq!(deserializer.column_count(), 2);
+    assert_eq!(deserializer.current_index(), 0);
+    assert_eq!(deserializer.current_column_name(), Some("id"));
+
+    let deserializer = PgRowDeserializer::builder()
+        .with_column_offset(1)
+        .build(&row);
+    assert_eq!(deserializer.column_count(), 2);
+    assert_eq!(deserializer.current_index(), 1);
+    assert_eq!(deserializer.current_column_name(), Some("name"));
+
+    let deserializer = PgRowDeserializer::builder()
+        .with_column_offset(2)
+        .build(&row);
+    assert_eq!(deserializer.current_column_name(), None);
+}
@@ -0,0 +1,100 @@
+mod util;
+
+use serde::de::{Deserializer, MapAccess, Visitor};
+use serde::Deserialize;
+use serde_sqlx::{from_pg_row, DeserializeError, PgValueDeserializer};
+use sqlx::postgres::PgRow;
+use sqlx::Row;
+use util::fetch_row;
+
+#[derive(Debug, PartialEq)]
+struct FullName(String);
+
+/// Reads `first_name` and `last_name`, whatever else the row holds
+fn full_name(row: &PgRow) -> Result<FullName, DeserializeError> {
+    let mut parts = Vec::new();
+    for column in ["first_name", "last_name"] {
+        let value = row.try_get_raw(column).unwrap();
+        parts.push(String::deserialize(PgValueDeserializer::new(value))?);
+    }
+
+    Ok(FullName(parts.join(" ")))
+}
+
+#[tokio::test]
+async fn two_columns_into_newtype() {
+    let row = fetch_row("SELECT 1 AS id, 'Ada' AS first_name, 'Lovelace' AS last_name").await;
+
+    assert_eq!(
+        full_name(&row).unwrap(),
+        FullName("Ada Lovelace".to_owned())
+    );
+}
+
+#[tokio::test]
+async fn errors_are_reported() {
+    let row = fetch_row("SELECT 'Ada' AS first_name, NULL::TEXT AS last_name").await;
+
+    assert!(matches!(
+        full_name(&row),
+        Err(DeserializeError::NullValue { .. })
+    ));
+}
+
+impl<'de> Deserialize<'de> for FullName {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FullNameVisitor;
+
+        impl<'de> Visitor<'de> for FullNameVisitor {
+            type Value = FullName;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("first_name and last_name columns")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<FullName, A::Error> {
+                let (mut first, mut last) = (None, None);
+                while let Some(column) = map.next_key::<String>()? {
+                    // Each value is read through a `PgValueDeserializer`
+                    match column.as_str() {
+                        "first_name" => first = Some(map.next_value::<String>()?),
+                        "last_name" => last = Some(map.next_value::<String>()?),
+                        _ => {
+                            map.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+
+                match (first, last) {
+                    (Some(first), Some(last)) => Ok(FullName(format!("{first} {last}"))),
+                    _ => Err(serde::de::Error::custom("missing a name column")),
+                }
+            }
+        }
+
+        deserializer.deserialize_map(FullNameVisitor)
+    }
+}
+
+#[tokio::test]
+async fn two_columns_through_visit_map() {
+    let row = fetch_row("SELECT 1 AS id, 'Ada' AS first_name, 'Lovelace' AS last_name").await;
+
+    let name: FullName = from_pg_row(row).unwrap();
+    assert_eq!(name, FullName("Ada Lovelace".to_owned()));
+}
+
+#[tokio::test]
+async fn debug_shows_the_type() {
+    let row = fetch_row("SELECT 'Ada' AS first_name, NULL::INT4 AS age").await;
+
+    let name = PgValueDeserializer::new(row.try_get_raw("first_name").unwrap());
+    assert_eq!(
+        format!("{name:?}"),
+        r#"PgValueDeserializer { pg_type: "TEXT", column: None, is_null: false, .. }"#
+    );
+
+    let age = PgValueDeserializer::new(row.try_get_raw("age").unwrap());
+    assert!(format!("{age:?}").contains(r#"pg_type: "INT4""#));
+    assert!(format!("{age:?}").contains("is_null: true"));
+}
@@ -0,0 +1,80 @@
+mod util;
+
+use serde::Deserialize;
+use serde_sqlx::{from_pg_row, from_pg_row_with_config, DeserializerConfig};
+use util::fetch_row;
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct Stats {
+    query_id: i64,
+    total_exec_time: f64,
+}
+
+fn config() -> DeserializerConfig {
+    DeserializerConfig {
+        snake_to_camel_columns: true,
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn snake_case_columns_into_camel_case_fields() {
+    let row = fetch_row("SELECT 7::INT8 AS query_id, 1.5::FLOAT8 AS total_exec_time").await;
+
+    let stats: Stats = from_pg_row_with_config(row, &config()).unwrap();
+    assert_eq!(
+        stats,
+        Stats {
+            query_id: 7,
+            total_exec_time: 1.5
+        }
+    );
+}
+
+#[tokio::test]
+async fn camel_case_fields_need_the_config() {
+    let row = fetch_row("SELECT 7::INT8 AS query_id, 1.5::FLOAT8 AS total_exec_time").await;
+
+    assert!(from_pg_row::<Stats>(row).is_err());
+}
+
+#[tokio::test]
+async fn leading_underscore_and_single_word_columns() {
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Row {
+        _private: i32,
+        #[serde(rename = "userName")]
+        user_name: String,
+        plain: bool,
+    }
+
+    let row = fetch_row("SELECT 1 AS _private, 'alice' AS user_name, true AS plain").await;
+
+    let row: Row = from_pg_row_with_config(row, &config()).unwrap();
+    assert_eq!(
+        row,
+        Row {
+            _private: 1,
+            user_name: "alice".to_owned(),
+            plain: true
+        }
+    );
+}
+
+#[tokio::test]
+async fn explicit_rename_works_without_the_config() {
+    #[derive(Debug, Deserialize, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    struct Stats {
+        #[serde(rename = "query_id")]
+        query_id: i64,
+        #[serde(rename = "total_exec_time")]
+        total_exec_time: f64,
+    }
+
+    let row = fetch_row("SELECT 7::INT8 AS query_id, 1.5::FLOAT8 AS total_exec_time").await;
+
+    let stats: Stats = from_pg_row(row).unwrap();
+    assert_eq!(stats.total_exec_time, 1.5);
+}
@@ -0,0 +1,73 @@
+mod util;
+
+use serde::Deserialize;
+use serde_sqlx::{from_pg_row_with_rename, DeserializerConfig, PgRowDeserializer};
+use util::fetch_row;
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct User {
+    id: i32,
+    name: String,
+}
+
+#[tokio::test]
+async fn strip_prefix() {
+    let row = fetch_row("SELECT 1 AS tbl_id, 'alice' AS tbl_name").await;
+
+    let user: User = from_pg_row_with_rename(row, |column| {
+        column.strip_prefix("tbl_").unwrap_or(column).to_owned()
+    })
+    .unwrap();
+    assert_eq!(
+        user,
+        User {
+            id: 1,
+            name: "alice".to_owned()
+        }
+    );
+}
+
+#[tokio::test]
+async fn camel_case() {
+    #[derive(Debug, Deserialize, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    struct Post {
+        created_at: String,
+        author_id: i32,
+    }
+
+    let row = fetch_row("SELECT '2024-01-01' AS created_at, 7 AS author_id").await;
+
+    let post: Post = from_pg_row_with_rename(row, |column| match column {
+        "created_at" => "createdAt".to_owned(),
+        "author_id" => "authorId".to_owned(),
+        other => other.to_owned(),
+    })
+    .unwrap();
+    assert_eq!(
+        post,
+        Post {
+            created_at: "2024-01-01".to_owned(),
+            author_id: 7
+        }
+    );
+}
+
+#[tokio::test]
+async fn renamed_columns_keep_other_config() {
+    let row = fetch_row(r#"SELECT 1 AS "TBL_ID", 'alice' AS "TBL_NAME""#).await;
+    let config = DeserializerConfig {
+        case_insensitive_columns: true,
+        ..Default::default()
+    };
+    let rename = |column: &str| column.strip_prefix("TBL_").unwrap_or(column).to_owned();
+
+    let user = User::deserialize(
+        PgRowDeserializer::builder()
+            .with_config(&config)
+            .with_rename(&rename)
+            .build(&row),
+    )
+    .unwrap();
+    assert_eq!(user.id, 1);
+}
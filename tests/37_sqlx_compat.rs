@@ -0,0 +1,72 @@
+#![cfg(feature = "sqlx-compat")]
+
+mod util;
+
+use serde::Deserialize;
+use serde_sqlx::sqlx_compat::SerdeFromRow;
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct User {
+    id: i32,
+    name: String,
+    tags: Vec<String>,
+}
+
+#[tokio::test]
+async fn query_as_with_wrapper() {
+    let conn = util::conn().await;
+
+    let users: Vec<SerdeFromRow<User>> = sqlx::query_as(
+        "SELECT i AS id, 'user ' || i AS name, ARRAY['t' || i] AS tags \
+         FROM generate_series(1, 2) i",
+    )
+    .fetch_all(&conn)
+    .await
+    .unwrap();
+
+    let users: Vec<User> = users.into_iter().map(SerdeFromRow::into_inner).collect();
+    assert_eq!(
+        users,
+        vec![
+            User {
+                id: 1,
+                name: "user 1".to_owned(),
+                tags: vec!["t1".to_owned()]
+            },
+            User {
+                id: 2,
+                name: "user 2".to_owned(),
+                tags: vec!["t2".to_owned()]
+            }
+        ]
+    );
+}
+
+#[tokio::test]
+async fn scalar_and_json_targets() {
+    let conn = util::conn().await;
+
+    let SerdeFromRow(count) = sqlx::query_as::<_, SerdeFromRow<i64>>("SELECT 3::INT8")
+        .fetch_one(&conn)
+        .await
+        .unwrap();
+    assert_eq!(count, 3);
+
+    let SerdeFromRow(value) =
+        sqlx::query_as::<_, SerdeFromRow<serde_json::Value>>("SELECT 1 AS a, 'b' AS b")
+            .fetch_one(&conn)
+            .await
+            .unwrap();
+    assert_eq!(value, serde_json::json!({"a": 1, "b": "b"}));
+}
+
+#[tokio::test]
+async fn errors_become_decode_errors() {
+    let conn = util::conn().await;
+
+    let err = sqlx::query_as::<_, SerdeFromRow<User>>("SELECT 1 AS id")
+        .fetch_one(&conn)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, sqlx::Error::Decode(_)), "{err:?}");
+}
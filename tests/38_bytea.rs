@@ -0,0 +1,58 @@
+mod util;
+
+use serde::Deserialize;
+use util::fetch_one;
+
+#[tokio::test]
+async fn bytea_as_vec() {
+    let bytes: Vec<u8> = fetch_one("SELECT '\\x00ff10'::BYTEA").await.unwrap();
+    assert_eq!(bytes, [0x00, 0xff, 0x10]);
+
+    let empty: Vec<u8> = fetch_one("SELECT '\\x'::BYTEA").await.unwrap();
+    assert!(empty.is_empty());
+}
+
+#[tokio::test]
+async fn bytea_as_array() {
+    let bytes: [u8; 4] = fetch_one("SELECT '\\xdeadbeef'::BYTEA").await.unwrap();
+    assert_eq!(bytes, [0xde, 0xad, 0xbe, 0xef]);
+
+    assert!(fetch_one::<[u8; 2]>("SELECT '\\xdeadbeef'::BYTEA")
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn bytea_as_bytes() {
+    let bytes: bytes::Bytes = fetch_one("SELECT 'abc'::BYTEA").await.unwrap();
+    assert_eq!(bytes, "abc");
+}
+
+#[tokio::test]
+async fn nullable_bytea() {
+    let bytes: Option<Vec<u8>> = fetch_one("SELECT NULL::BYTEA").await.unwrap();
+    assert_eq!(bytes, None);
+}
+
+#[tokio::test]
+async fn bytea_struct_field() {
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct File {
+        name: String,
+        data: Vec<u8>,
+        checksum: [u8; 2],
+    }
+
+    let file: File =
+        fetch_one("SELECT 'a.bin' AS name, '\\x0102'::BYTEA AS data, '\\xabcd'::BYTEA AS checksum")
+            .await
+            .unwrap();
+    assert_eq!(
+        file,
+        File {
+            name: "a.bin".to_owned(),
+            data: vec![1, 2],
+            checksum: [0xab, 0xcd]
+        }
+    );
+}
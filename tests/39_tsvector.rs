@@ -0,0 +1,72 @@
+mod util;
+
+use serde::Deserialize;
+use util::fetch_one;
+
+const DOCUMENT: &str = "SELECT to_tsvector('english', 'the cat sat on the mat')";
+
+#[tokio::test]
+async fn tsvector_as_string() {
+    let text: String = fetch_one(DOCUMENT).await.unwrap();
+    assert_eq!(text, "'cat':2 'mat':6 'sat':3");
+}
+
+#[tokio::test]
+async fn tsvector_as_lexemes() {
+    let lexemes: Vec<String> = fetch_one(DOCUMENT).await.unwrap();
+    assert_eq!(lexemes, ["cat", "mat", "sat"]);
+}
+
+#[tokio::test]
+async fn weights_and_quotes_round_trip() {
+    let query = r#"SELECT $$'it''s':1A,3 'back\\slash' 'plain':2C$$::TSVECTOR"#;
+
+    let text: String = fetch_one(query).await.unwrap();
+    assert_eq!(text, r#"'back\\slash' 'it''s':1A,3 'plain':2C"#);
+
+    let lexemes: Vec<String> = fetch_one(query).await.unwrap();
+    assert_eq!(lexemes, [r"back\slash", "it's", "plain"]);
+}
+
+#[tokio::test]
+async fn tsvector_struct_fields() {
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Document {
+        search: String,
+        lexemes: Vec<String>,
+        empty: Vec<String>,
+    }
+
+    let document: Document = fetch_one(
+        "SELECT to_tsvector('english', 'cats') AS search, \
+                to_tsvector('english', 'dogs') AS lexemes, \
+                ''::TSVECTOR AS empty",
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        document,
+        Document {
+            search: "'cat':1".to_owned(),
+            lexemes: vec!["dog".to_owned()],
+            empty: vec![],
+        }
+    );
+}
+
+#[tokio::test]
+async fn tsvector_in_text_format() {
+    let conn = util::conn().await;
+    let row = sqlx::raw_sql(r#"SELECT $$'it''s':1A,3 'cat'$$::TSVECTOR AS doc"#)
+        .fetch_one(&conn)
+        .await
+        .unwrap();
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Row {
+        doc: String,
+    }
+
+    let row: Row = serde_sqlx::from_pg_row(row).unwrap();
+    assert_eq!(row.doc, "'cat' 'it''s':1A,3");
+}
@@ -0,0 +1,51 @@
+mod util;
+
+use serde::Deserialize;
+use tokio::sync::OnceCell;
+use util::{execute, fetch_one};
+
+/// Tests in this file run concurrently, and racing `CREATE EXTENSION`s can fail
+async fn create_citext_extension() {
+    static CREATED: OnceCell<()> = OnceCell::const_new();
+
+    CREATED
+        .get_or_init(|| execute("CREATE EXTENSION IF NOT EXISTS citext"))
+        .await;
+}
+
+#[tokio::test]
+async fn citext_as_string() {
+    create_citext_extension().await;
+
+    let email: String = fetch_one("SELECT 'Alice@Example.com'::CITEXT")
+        .await
+        .unwrap();
+    assert_eq!(email, "Alice@Example.com");
+}
+
+#[tokio::test]
+async fn citext_fields() {
+    create_citext_extension().await;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct User {
+        email: String,
+        nickname: Option<String>,
+        tags: Vec<String>,
+    }
+
+    let user: User = fetch_one(
+        "SELECT 'Bob@Example.com'::CITEXT AS email, NULL::CITEXT AS nickname, \
+                'Admin'::CITEXT AS tags",
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        user,
+        User {
+            email: "Bob@Example.com".to_owned(),
+            nickname: None,
+            tags: vec!["Admin".to_owned()],
+        }
+    );
+}
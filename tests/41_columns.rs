@@ -0,0 +1,69 @@
+mod util;
+
+use serde::Deserialize;
+use serde_sqlx::{
+    column_type_name, from_pg_row_ref, has_column, PgRowInspector, PgValueDeserializer,
+};
+use util::fetch_row;
+
+#[tokio::test]
+async fn present_and_missing_columns() {
+    let row = fetch_row("SELECT 1 AS id, 'alice' AS name, ARRAY[1] AS ids").await;
+
+    assert!(has_column(&row, "id"));
+    assert!(has_column(&row, "ids"));
+    assert!(!has_column(&row, "email"));
+    // Column names are matched exactly
+    assert!(!has_column(&row, "ID"));
+
+    assert_eq!(column_type_name(&row, "id"), Some("INT4"));
+    assert_eq!(column_type_name(&row, "name"), Some("TEXT"));
+    assert_eq!(column_type_name(&row, "ids"), Some("INT4[]"));
+    assert_eq!(column_type_name(&row, "email"), None);
+}
+
+#[tokio::test]
+async fn pick_target_by_column() {
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Summary {
+        id: i32,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Detailed {
+        id: i32,
+        email: String,
+    }
+
+    for query in ["SELECT 1 AS id", "SELECT 1 AS id, 'a@example.com' AS email"] {
+        let row = fetch_row(query).await;
+
+        if has_column(&row, "email") {
+            let detailed: Detailed = from_pg_row_ref(&row).unwrap();
+            assert_eq!(detailed.email, "a@example.com");
+        } else {
+            let summary: Summary = from_pg_row_ref(&row).unwrap();
+            assert_eq!(summary, Summary { id: 1 });
+        }
+    }
+}
+
+#[tokio::test]
+async fn inspector() {
+    let row = fetch_row("SELECT 1 AS id, NULL::TEXT AS bio, '{1,2}'::INT8[] AS ids").await;
+    let inspector = PgRowInspector(&row);
+
+    assert_eq!(inspector.column_count(), 3);
+    assert_eq!(
+        inspector.columns().collect::<Vec<_>>(),
+        [("id", "INT4"), ("bio", "TEXT"), ("ids", "INT8[]")]
+    );
+
+    assert!(inspector.is_null("bio"));
+    assert!(!inspector.is_null("id"));
+    assert!(!inspector.is_null("missing"));
+
+    let id = inspector.raw_value("id").unwrap();
+    assert_eq!(i32::deserialize(PgValueDeserializer::new(id)).unwrap(), 1);
+    assert!(inspector.raw_value("missing").is_none());
+}
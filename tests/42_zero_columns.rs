@@ -0,0 +1,39 @@
+mod util;
+
+use serde::Deserialize;
+use serde_sqlx::from_pg_row;
+use util::{fetch_all, fetch_row};
+
+#[tokio::test]
+async fn zero_columns_into_unit() {
+    let row = fetch_row("SELECT").await;
+
+    let unit: () = from_pg_row(row).unwrap();
+    assert_eq!(unit, ());
+}
+
+#[tokio::test]
+async fn zero_columns_into_optional_unit() {
+    let row = fetch_row("SELECT").await;
+
+    let unit: Option<()> = from_pg_row(row).unwrap();
+    assert_eq!(unit, Some(()));
+}
+
+#[tokio::test]
+async fn zero_columns_into_unit_struct() {
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Done;
+
+    let rows: Vec<Done> = fetch_all("SELECT FROM generate_series(1, 2)")
+        .await
+        .unwrap();
+    assert_eq!(rows, [Done, Done]);
+}
+
+#[tokio::test]
+async fn zero_columns_into_a_value_fails() {
+    let row = fetch_row("SELECT").await;
+
+    assert!(from_pg_row::<i32>(row).is_err());
+}
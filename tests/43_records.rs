@@ -0,0 +1,126 @@
+mod util;
+
+use serde::Deserialize;
+use tokio::sync::OnceCell;
+use util::{execute, fetch_one};
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Greeting {
+    id: i32,
+    text: String,
+}
+
+#[tokio::test]
+async fn anonymous_record_into_struct() {
+    let greeting: Greeting = fetch_one("SELECT ROW(1, 'hello')::RECORD").await.unwrap();
+
+    assert_eq!(
+        greeting,
+        Greeting {
+            id: 1,
+            text: "hello".to_owned()
+        }
+    );
+}
+
+#[tokio::test]
+async fn anonymous_record_into_tuple() {
+    let tuple: (i32, String, Option<bool>) = fetch_one("SELECT ROW(1, 'hello', NULL::BOOL)")
+        .await
+        .unwrap();
+
+    assert_eq!(tuple, (1, "hello".to_owned(), None));
+}
+
+#[tokio::test]
+async fn record_struct_field() {
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Row {
+        id: i32,
+        greeting: Greeting,
+        missing: Option<Greeting>,
+    }
+
+    let row: Row =
+        fetch_one("SELECT 7 AS id, ROW(1, 'hello') AS greeting, NULL::RECORD AS missing")
+            .await
+            .unwrap();
+
+    assert_eq!(
+        row,
+        Row {
+            id: 7,
+            greeting: Greeting {
+                id: 1,
+                text: "hello".to_owned()
+            },
+            missing: None
+        }
+    );
+}
+
+#[tokio::test]
+async fn nested_records_and_array_fields() {
+    let value: (i64, (String, Vec<i32>)) = fetch_one("SELECT ROW(1::INT8, ROW('a', ARRAY[1, 2]))")
+        .await
+        .unwrap();
+
+    assert_eq!(value, (1, ("a".to_owned(), vec![1, 2])));
+}
+
+/// Tests in this file run concurrently, and racing `CREATE TYPE`s can fail
+async fn create_address_type() {
+    static CREATED: OnceCell<()> = OnceCell::const_new();
+
+    CREATED
+        .get_or_init(|| {
+            execute(
+                "DO $$ BEGIN
+                    CREATE TYPE serde_sqlx_address AS (street TEXT, number INT4);
+                EXCEPTION WHEN duplicate_object THEN NULL;
+                END $$",
+            )
+        })
+        .await;
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Address {
+    number: i32,
+    street: String,
+}
+
+#[tokio::test]
+async fn composite_type_by_field_name() {
+    create_address_type().await;
+
+    let address: Address = fetch_one("SELECT ROW('Main St', 12)::serde_sqlx_address")
+        .await
+        .unwrap();
+
+    assert_eq!(
+        address,
+        Address {
+            number: 12,
+            street: "Main St".to_owned()
+        }
+    );
+}
+
+#[tokio::test]
+async fn composite_type_as_json_value_and_tuple() {
+    create_address_type().await;
+
+    let value: serde_json::Value = fetch_one("SELECT ROW('Main St', 12)::serde_sqlx_address")
+        .await
+        .unwrap();
+    assert_eq!(
+        value,
+        serde_json::json!({ "street": "Main St", "number": 12 })
+    );
+
+    let tuple: (String, i32) = fetch_one("SELECT ROW('Main St', 12)::serde_sqlx_address")
+        .await
+        .unwrap();
+    assert_eq!(tuple, ("Main St".to_owned(), 12));
+}
@@ -0,0 +1,65 @@
+#![cfg(feature = "ltree")]
+
+mod util;
+
+use serde::Deserialize;
+use tokio::sync::OnceCell;
+use util::fetch_one;
+
+/// Whether the `ltree` extension could be created. Tests in this file run
+/// concurrently, and racing `CREATE EXTENSION`s can fail
+async fn ltree_available() -> bool {
+    static AVAILABLE: OnceCell<bool> = OnceCell::const_new();
+
+    *AVAILABLE
+        .get_or_init(|| async {
+            let conn = util::conn().await;
+            let created = sqlx::query("CREATE EXTENSION IF NOT EXISTS ltree")
+                .execute(&conn)
+                .await;
+
+            created.is_ok()
+        })
+        .await
+}
+
+#[tokio::test]
+async fn ltree_as_string() {
+    if !ltree_available().await {
+        return;
+    }
+
+    let path: String = fetch_one("SELECT 'Top.Science.Astronomy'::LTREE")
+        .await
+        .unwrap();
+    assert_eq!(path, "Top.Science.Astronomy");
+}
+
+#[tokio::test]
+async fn lquery_and_ltxtquery_as_strings() {
+    if !ltree_available().await {
+        return;
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Queries {
+        lquery: String,
+        ltxtquery: String,
+        missing: Option<String>,
+    }
+
+    let queries: Queries = fetch_one(
+        "SELECT '*.Science.*'::LQUERY AS lquery, 'Europe & Russia@*'::LTXTQUERY AS ltxtquery, \
+                NULL::LTREE AS missing",
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        queries,
+        Queries {
+            lquery: "*.Science.*".to_owned(),
+            ltxtquery: "Europe & Russia@*".to_owned(),
+            missing: None,
+        }
+    );
+}
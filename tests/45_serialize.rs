@@ -0,0 +1,47 @@
+mod util;
+
+use serde::Deserialize;
+use serde_json::json;
+use serde_sqlx::{from_pg_row, row_to_json, PgRowSerializer};
+use util::fetch_row;
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct User {
+    id: i32,
+    name: String,
+    tags: Vec<String>,
+    email: Option<String>,
+}
+
+#[tokio::test]
+async fn serializes_as_a_map() {
+    let row =
+        fetch_row("SELECT 1 AS id, 'alice' AS name, ARRAY['a', 'b'] AS tags, NULL::TEXT AS email")
+            .await;
+
+    let json = serde_json::to_value(PgRowSerializer::new(&row)).unwrap();
+    assert_eq!(
+        json,
+        json!({ "id": 1, "name": "alice", "tags": ["a", "b"], "email": null })
+    );
+    assert_eq!(json, row_to_json(&row).unwrap());
+}
+
+#[tokio::test]
+async fn round_trips_through_json() {
+    let row =
+        fetch_row("SELECT 1 AS id, 'alice' AS name, ARRAY['a', 'b'] AS tags, 'a@b.c' AS email")
+            .await;
+
+    let text = serde_json::to_string(&PgRowSerializer::new(&row)).unwrap();
+    let user: User = serde_json::from_str(&text).unwrap();
+    assert_eq!(user, from_pg_row(row).unwrap());
+}
+
+#[tokio::test]
+async fn keeps_column_order() {
+    let row = fetch_row("SELECT 2 AS b, 1 AS a, '{\"x\": [1]}'::JSONB AS c").await;
+
+    let text = serde_json::to_string(&PgRowSerializer::new(&row)).unwrap();
+    assert_eq!(text, r#"{"b":2,"a":1,"c":{"x":[1]}}"#);
+}
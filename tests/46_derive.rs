@@ -0,0 +1,62 @@
+#![cfg(feature = "derive")]
+
+mod util;
+
+use serde_sqlx::FromPgRow;
+use util::fetch_row;
+
+#[derive(Debug, FromPgRow, PartialEq)]
+struct User {
+    id: i32,
+    #[from_pg_row(rename = "user_name")]
+    name: String,
+    #[from_pg_row(column = "tags_list")]
+    tags: Vec<String>,
+    email: Option<String>,
+}
+
+#[tokio::test]
+async fn reads_fields_by_column() {
+    let row = fetch_row(
+        "SELECT 'a@b.c' AS email, ARRAY['a'] AS tags_list, 'alice' AS user_name, 1 AS id",
+    )
+    .await;
+
+    assert_eq!(
+        User::from_pg_row(&row).unwrap(),
+        User {
+            id: 1,
+            name: "alice".to_owned(),
+            tags: vec!["a".to_owned()],
+            email: Some("a@b.c".to_owned()),
+        }
+    );
+}
+
+#[tokio::test]
+async fn null_and_missing_columns() {
+    let row = fetch_row(
+        "SELECT 1 AS id, 'alice' AS user_name, ARRAY[]::TEXT[] AS tags_list, NULL::TEXT AS email",
+    )
+    .await;
+    assert_eq!(User::from_pg_row(&row).unwrap().email, None);
+
+    let row = fetch_row("SELECT 1 AS id, 'alice' AS name").await;
+    let err = User::from_pg_row(&row).unwrap_err();
+    assert_eq!(err.to_string(), "missing column 'user_name'");
+}
+
+#[derive(Debug, FromPgRow, PartialEq)]
+struct Wrapper<T> {
+    value: T,
+}
+
+#[tokio::test]
+async fn generic_struct() {
+    let row = fetch_row("SELECT 5::INT8 AS value").await;
+
+    assert_eq!(
+        Wrapper::<i64>::from_pg_row(&row).unwrap(),
+        Wrapper { value: 5 }
+    );
+}
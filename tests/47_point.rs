@@ -0,0 +1,54 @@
+mod util;
+
+use serde::Deserialize;
+use util::fetch_one;
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+#[tokio::test]
+async fn point_as_tuple_and_array() {
+    let point: (f64, f64) = fetch_one("SELECT point(1.0, 2.0)").await.unwrap();
+    assert_eq!(point, (1.0, 2.0));
+
+    let point: [f64; 2] = fetch_one("SELECT '(-1.5, 3)'::POINT").await.unwrap();
+    assert_eq!(point, [-1.5, 3.0]);
+}
+
+#[tokio::test]
+async fn point_field() {
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Place {
+        name: String,
+        location: Point,
+        corner: (f64, f64),
+        missing: Option<Point>,
+    }
+
+    let place: Place = fetch_one(
+        "SELECT 'home' AS name, point(1.0, 2.0) AS location, point(0, 0) AS corner, \
+                NULL::POINT AS missing",
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        place,
+        Place {
+            name: "home".to_owned(),
+            location: Point { x: 1.0, y: 2.0 },
+            corner: (0.0, 0.0),
+            missing: None,
+        }
+    );
+}
+
+#[tokio::test]
+async fn point_needs_two_elements() {
+    let err = fetch_one::<(f64, f64, f64)>("SELECT point(1.0, 2.0)")
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("POINT"), "{err}");
+}
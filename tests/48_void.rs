@@ -0,0 +1,26 @@
+mod util;
+
+use serde::Deserialize;
+use util::{fetch_one, fetch_row};
+
+#[tokio::test]
+async fn void_function_into_unit() {
+    let () = fetch_one("SELECT pg_sleep(0)").await.unwrap();
+
+    let row = fetch_row("SELECT pg_sleep(0) AS slept").await;
+    assert_eq!(serde_sqlx::column_type_name(&row, "slept"), Some("VOID"));
+}
+
+#[tokio::test]
+async fn void_column_in_a_struct() {
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Call {
+        id: i32,
+        result: (),
+    }
+
+    let call: Call = fetch_one("SELECT 1 AS id, pg_sleep(0) AS result")
+        .await
+        .unwrap();
+    assert_eq!(call, Call { id: 1, result: () });
+}
@@ -0,0 +1,56 @@
+mod util;
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use serde_sqlx::{from_pg_row, from_pg_row_lenient};
+use util::fetch_row;
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+struct User {
+    id: i32,
+    name: String,
+}
+
+#[tokio::test]
+async fn extra_columns_are_skipped() {
+    let query = "SELECT 1 AS id, 'secret' AS password, 'alice' AS name, now() AS created_at";
+
+    let err = from_pg_row::<User>(fetch_row(query).await).unwrap_err();
+    assert!(err.to_string().contains("unknown field"), "{err}");
+
+    let user: User = from_pg_row_lenient(fetch_row(query).await).unwrap();
+    assert_eq!(
+        user,
+        User {
+            id: 1,
+            name: "alice".to_owned()
+        }
+    );
+}
+
+#[tokio::test]
+async fn skipped_columns_are_not_decoded() {
+    // BOX isn't supported, but is never looked at
+    let row = fetch_row("SELECT 1 AS id, 'alice' AS name, box '((0,0),(1,1))' AS area").await;
+
+    let user: User = from_pg_row_lenient(row).unwrap();
+    assert_eq!(user.id, 1);
+}
+
+#[tokio::test]
+async fn missing_fields_still_fail() {
+    let row = fetch_row("SELECT 1 AS id, 'x' AS other").await;
+
+    let err = from_pg_row_lenient::<User>(row).unwrap_err();
+    assert!(err.to_string().contains("missing field `name`"), "{err}");
+}
+
+#[tokio::test]
+async fn maps_keep_every_column() {
+    let row = fetch_row("SELECT 1 AS id, 2 AS other").await;
+
+    let map: HashMap<String, i32> = from_pg_row_lenient(row).unwrap();
+    assert_eq!(map.len(), 2);
+}
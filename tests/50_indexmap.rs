@@ -0,0 +1,41 @@
+mod util;
+
+use indexmap::IndexMap;
+use serde_json::Value;
+use util::{fetch_all, fetch_one};
+
+#[tokio::test]
+async fn entries_follow_the_select_list() {
+    let rows: Vec<IndexMap<String, String>> = fetch_all(
+        "SELECT 'z' AS zeta, 'a' AS alpha, 'm' AS mu \
+         UNION ALL SELECT 'z2', 'a2', 'm2'",
+    )
+    .await
+    .unwrap();
+
+    for row in &rows {
+        assert_eq!(
+            row.keys().map(String::as_str).collect::<Vec<_>>(),
+            ["zeta", "alpha", "mu"]
+        );
+    }
+    assert_eq!(rows[1]["alpha"], "a2");
+}
+
+#[tokio::test]
+async fn mixed_values_keep_their_order() {
+    let row: IndexMap<String, Value> =
+        fetch_one("SELECT 2 AS b, 'x' AS a, NULL::TEXT AS c, ARRAY[1] AS d")
+            .await
+            .unwrap();
+
+    assert_eq!(
+        row.into_iter().collect::<Vec<_>>(),
+        [
+            ("b".to_owned(), Value::from(2)),
+            ("a".to_owned(), Value::from("x")),
+            ("c".to_owned(), Value::Null),
+            ("d".to_owned(), Value::from(vec![1])),
+        ]
+    );
+}
@@ -0,0 +1,107 @@
+#![cfg(feature = "geometry")]
+
+mod util;
+
+use serde::Deserialize;
+use serde_json::{json, Value};
+use util::fetch_one;
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+#[tokio::test]
+async fn point_array() {
+    let points: Vec<(f64, f64)> = fetch_one("SELECT ARRAY[point(1, 2), point(3.5, -4)]")
+        .await
+        .unwrap();
+    assert_eq!(points, [(1.0, 2.0), (3.5, -4.0)]);
+
+    let points: Vec<Option<Point>> = fetch_one("SELECT ARRAY[point(0, 1), NULL]::POINT[]")
+        .await
+        .unwrap();
+    assert_eq!(points, [Some(Point { x: 0.0, y: 1.0 }), None]);
+}
+
+#[tokio::test]
+async fn geometric_values() {
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Circle {
+        x: f64,
+        y: f64,
+        radius: f64,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Path {
+        closed: bool,
+        points: Vec<Point>,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Shapes {
+        line: [f64; 3],
+        segment: ((f64, f64), (f64, f64)),
+        area: [(f64, f64); 2],
+        path: Path,
+        polygon: Vec<(f64, f64)>,
+        circle: Circle,
+    }
+
+    let shapes: Shapes = fetch_one(
+        "SELECT line '{1, -1, 0}' AS line, lseg '[(0, 0), (1, 1)]' AS segment, \
+                box '((0, 0), (2, 3))' AS area, path '[(0, 0), (1, 1)]' AS path, \
+                polygon '((0, 0), (1, 0), (0, 1))' AS polygon, circle '<(1, 2), 3>' AS circle",
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        shapes,
+        Shapes {
+            line: [1.0, -1.0, 0.0],
+            segment: ((0.0, 0.0), (1.0, 1.0)),
+            area: [(2.0, 3.0), (0.0, 0.0)],
+            path: Path {
+                closed: false,
+                points: vec![Point { x: 0.0, y: 0.0 }, Point { x: 1.0, y: 1.0 }],
+            },
+            polygon: vec![(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)],
+            circle: Circle {
+                x: 1.0,
+                y: 2.0,
+                radius: 3.0,
+            },
+        }
+    );
+}
+
+#[tokio::test]
+async fn lone_geometric_column() {
+    let area: [(f64, f64); 2] = fetch_one("SELECT box '((1, 1), (0, 0))'").await.unwrap();
+    assert_eq!(area, [(1.0, 1.0), (0.0, 0.0)]);
+}
+
+#[tokio::test]
+async fn geometric_arrays_as_json() {
+    let row: Value = fetch_one(
+        "SELECT ARRAY[circle '<(0, 0), 1>'] AS circles, ARRAY[box '((1, 1), (0, 0))'] AS boxes, \
+                ARRAY[lseg '[(0, 0), (1, 1)]'] AS segments, ARRAY[line '{0, 1, 2}'] AS lines, \
+                ARRAY[path '((0, 0), (1, 1))'] AS paths, \
+                ARRAY[polygon '((0, 0), (1, 1), (1, 0))'] AS polygons",
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        row,
+        json!({
+            "circles": [[0.0, 0.0, 1.0]],
+            "boxes": [[[1.0, 1.0], [0.0, 0.0]]],
+            "segments": [[[0.0, 0.0], [1.0, 1.0]]],
+            "lines": [[0.0, 1.0, 2.0]],
+            "paths": [{ "closed": true, "points": [[0.0, 0.0], [1.0, 1.0]] }],
+            "polygons": [[[0.0, 0.0], [1.0, 1.0], [1.0, 0.0]]],
+        })
+    );
+}
@@ -0,0 +1,160 @@
+mod util;
+
+use serde::Deserialize;
+use util::{fetch_one, fetch_row};
+
+#[tokio::test]
+async fn pg_lsn_as_u64() {
+    let lsn: u64 = fetch_one("SELECT '16/B374D848'::PG_LSN").await.unwrap();
+    assert_eq!(lsn, 0x16_B374_D848);
+
+    let current: u64 = fetch_one("SELECT pg_current_wal_lsn()::PG_LSN")
+        .await
+        .unwrap();
+    assert!(current > 0);
+}
+
+#[tokio::test]
+async fn pg_lsn_struct_field() {
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Slot {
+        name: String,
+        confirmed: Option<u64>,
+        restart: Option<u64>,
+    }
+
+    let slot: Slot =
+        fetch_one("SELECT 'main' AS name, '0/0'::PG_LSN AS confirmed, NULL::PG_LSN AS restart")
+            .await
+            .unwrap();
+    assert_eq!(
+        slot,
+        Slot {
+            name: "main".to_owned(),
+            confirmed: Some(0),
+            restart: None,
+        }
+    );
+}
+
+#[tokio::test]
+async fn pg_lsn_as_json() {
+    let row = fetch_row("SELECT '1/0'::PG_LSN AS lsn").await;
+    assert_eq!(serde_sqlx::row_to_json(&row).unwrap()["lsn"], 1u64 << 32);
+}
+
+#[tokio::test]
+async fn xid_as_u32() {
+    let xid: u32 = fetch_one("SELECT '4294967295'::XID").await.unwrap();
+    assert_eq!(xid, u32::MAX);
+
+    let xid: u64 = fetch_one("SELECT '42'::XID").await.unwrap();
+    assert_eq!(xid, 42);
+}
+
+#[tokio::test]
+async fn xid_from_a_system_view() {
+    #[derive(Debug, Deserialize)]
+    struct Activity {
+        pid: i32,
+        backend_xmin: Option<u32>,
+    }
+
+    let activity: Activity =
+        fetch_one("SELECT pid, backend_xmin FROM pg_stat_activity WHERE pid = pg_backend_pid()")
+            .await
+            .unwrap();
+    assert!(activity.pid > 0);
+    assert!(activity.backend_xmin.is_some());
+}
+
+#[tokio::test]
+async fn regclass_and_regtype_as_oids() {
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Catalog {
+        relation: u32,
+        relation_oid: u32,
+        kind: u32,
+    }
+
+    let catalog: Catalog = fetch_one(
+        "SELECT 'pg_class'::REGCLASS AS relation, 'pg_class'::REGCLASS::OID AS relation_oid, \
+                'int4'::REGTYPE AS kind",
+    )
+    .await
+    .unwrap();
+    assert_eq!(catalog.relation, catalog.relation_oid);
+    // INT4's OID
+    assert_eq!(catalog.kind, 23);
+}
+
+#[tokio::test]
+async fn regclass_names_through_text() {
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Column {
+        table: String,
+        kind: String,
+    }
+
+    let column: Column = fetch_one(
+        "SELECT attrelid::REGCLASS::TEXT AS table, atttypid::REGTYPE::TEXT AS kind \
+         FROM pg_attribute WHERE attrelid = 'pg_class'::REGCLASS AND attname = 'relname'",
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        column,
+        Column {
+            table: "pg_class".to_owned(),
+            kind: "name".to_owned()
+        }
+    );
+
+    // Without the cast, only the OID is sent
+    let err = fetch_one::<String>("SELECT 'pg_class'::REGCLASS")
+        .await
+        .unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("cannot be deserialized into String"),
+        "{err}"
+    );
+
+    // The text protocol does send the name
+    let conn = util::conn().await;
+    let row = sqlx::raw_sql("SELECT 'pg_class'::REGCLASS AS relation")
+        .fetch_one(&conn)
+        .await
+        .unwrap();
+    let relation: String = serde_sqlx::from_pg_row(row).unwrap();
+    assert_eq!(relation, "pg_class");
+}
+
+#[tokio::test]
+async fn uncast_regclass_into_string_field() {
+    #[derive(Debug, Deserialize)]
+    #[allow(dead_code)]
+    struct Relation {
+        name: String,
+    }
+
+    let row = fetch_row("SELECT 'pg_class'::REGCLASS AS name").await;
+    let err = serde_sqlx::from_pg_row::<Relation>(row).unwrap_err();
+    assert!(
+        matches!(
+            &err,
+            serde_sqlx::DeserializeError::TypeMismatch { column, pg_type, target_type: "String" }
+                if column == "name" && pg_type == "regclass"
+        ),
+        "{err:?}"
+    );
+
+    // The text protocol has the name
+    let conn = util::conn().await;
+    let row = sqlx::raw_sql("SELECT 'pg_class'::REGCLASS AS name")
+        .fetch_one(&conn)
+        .await
+        .unwrap();
+    let relation: Relation = serde_sqlx::from_pg_row(row).unwrap();
+    assert_eq!(relation.name, "pg_class");
+}
@@ -0,0 +1,54 @@
+mod util;
+
+use serde_sqlx::{from_pg_row_column, from_pg_row_column_by_name};
+use util::fetch_row;
+
+#[tokio::test]
+async fn scalars_by_index() {
+    let row = fetch_row(
+        "SELECT true AS b, 2::INT2 AS s, 4::INT4 AS i, 8::INT8 AS l, \
+         1.5::FLOAT4 AS f, 2.5::FLOAT8 AS d, 'text' AS t, 'c'::CHAR AS c, \
+         NULL::INT4 AS n, ARRAY[1, 2] AS a",
+    )
+    .await;
+
+    assert!(from_pg_row_column::<bool>(&row, 0).unwrap());
+    assert_eq!(from_pg_row_column::<i16>(&row, 1).unwrap(), 2);
+    assert_eq!(from_pg_row_column::<i32>(&row, 2).unwrap(), 4);
+    assert_eq!(from_pg_row_column::<i64>(&row, 3).unwrap(), 8);
+    assert_eq!(from_pg_row_column::<f32>(&row, 4).unwrap(), 1.5);
+    assert_eq!(from_pg_row_column::<f64>(&row, 5).unwrap(), 2.5);
+    assert_eq!(from_pg_row_column::<String>(&row, 6).unwrap(), "text");
+    assert_eq!(from_pg_row_column::<char>(&row, 7).unwrap(), 'c');
+    assert_eq!(from_pg_row_column::<Option<i32>>(&row, 8).unwrap(), None);
+    assert_eq!(from_pg_row_column::<Vec<i32>>(&row, 9).unwrap(), [1, 2]);
+}
+
+#[tokio::test]
+async fn by_name() {
+    let row = fetch_row("SELECT 1 AS id, 'alice' AS name").await;
+
+    assert_eq!(from_pg_row_column_by_name::<i32>(&row, "id").unwrap(), 1);
+    assert_eq!(
+        from_pg_row_column_by_name::<String>(&row, "name").unwrap(),
+        "alice"
+    );
+
+    let err = from_pg_row_column_by_name::<String>(&row, "email").unwrap_err();
+    assert!(err.to_string().contains("missing column 'email'"), "{err}");
+}
+
+#[tokio::test]
+async fn out_of_range_index() {
+    let row = fetch_row("SELECT 1 AS id").await;
+
+    from_pg_row_column::<i32>(&row, 1).unwrap_err();
+}
+
+#[tokio::test]
+async fn error_names_the_column() {
+    let row = fetch_row("SELECT 'alice' AS name").await;
+
+    let err = from_pg_row_column::<i32>(&row, 0).unwrap_err();
+    assert!(err.to_string().contains("name"), "{err}");
+}
@@ -0,0 +1,89 @@
+mod util;
+
+use serde_json::{json, Value};
+use serde_sqlx::pg_value_to_json;
+use sqlx::postgres::PgRow;
+use sqlx::Row;
+use util::fetch_row;
+
+fn values(row: &PgRow) -> Vec<Value> {
+    (0..row.len())
+        .map(|index| pg_value_to_json(row.try_get_raw(index).unwrap()).unwrap())
+        .collect()
+}
+
+#[tokio::test]
+async fn scalars() {
+    let row = fetch_row(
+        r#"SELECT NULL::INT4, true, 1::INT2, 2::INT4, 3::INT8, 1.5::FLOAT4, 2.5::FLOAT8,
+                  42::OID, 'text'::TEXT, 'vc'::VARCHAR, 'bp'::CHAR(3), 'nm'::NAME,
+                  'x'::"char", 'a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11'::UUID"#,
+    )
+    .await;
+
+    assert_eq!(
+        values(&row),
+        [
+            Value::Null,
+            json!(true),
+            json!(1),
+            json!(2),
+            json!(3),
+            json!(1.5),
+            json!(2.5),
+            json!(42),
+            json!("text"),
+            json!("vc"),
+            json!("bp "),
+            json!("nm"),
+            json!("x"),
+            json!("a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11"),
+        ]
+    );
+}
+
+// With `time`, dates and times are in the `time` crate's formats
+#[cfg(not(feature = "time"))]
+#[tokio::test]
+async fn dates_network_and_bytes() {
+    let row = fetch_row(
+        r#"SELECT '2024-01-02'::DATE, '03:04:05'::TIME, '2024-01-02 03:04:05'::TIMESTAMP,
+                  '2024-01-02 03:04:05+00'::TIMESTAMPTZ, '10.0.0.1'::INET,
+                  '08:00:2b:01:02:03'::MACADDR, '\x0102'::BYTEA, B'101'"#,
+    )
+    .await;
+
+    assert_eq!(
+        values(&row),
+        [
+            json!("2024-01-02"),
+            json!("03:04:05"),
+            json!("2024-01-02T03:04:05"),
+            json!("2024-01-02T03:04:05+00:00"),
+            json!("10.0.0.1"),
+            json!("08:00:2b:01:02:03"),
+            json!([1, 2]),
+            json!("101"),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn arrays_json_and_records() {
+    let row = fetch_row(
+        r#"SELECT ARRAY[1, NULL, 3], ARRAY['a', 'b'], '{"x": [1, 2]}'::JSONB,
+                  '[true]'::JSON, ROW(1, 'a')"#,
+    )
+    .await;
+
+    assert_eq!(
+        values(&row),
+        [
+            json!([1, null, 3]),
+            json!(["a", "b"]),
+            json!({ "x": [1, 2] }),
+            json!([true]),
+            json!([1, "a"]),
+        ]
+    );
+}
@@ -0,0 +1,56 @@
+mod util;
+
+use serde::Deserialize;
+use serde_json::{json, Value};
+use serde_sqlx::PgRowColumns;
+use util::fetch_row;
+
+#[tokio::test]
+async fn yields_each_column_in_order() {
+    let row = fetch_row("SELECT 1 AS id, 'alice' AS name, NULL::TEXT AS bio").await;
+
+    let columns = PgRowColumns::new(&row)
+        .map(|(name, value)| Ok((name, Value::deserialize(value)?)))
+        .collect::<Result<Vec<_>, serde_sqlx::DeserializeError>>()
+        .unwrap();
+    assert_eq!(
+        columns,
+        [
+            ("id", json!(1)),
+            ("name", json!("alice")),
+            ("bio", Value::Null)
+        ]
+    );
+}
+
+#[tokio::test]
+async fn typed_values_and_length() {
+    let row = fetch_row("SELECT 1 AS id, 'alice' AS name").await;
+
+    let mut columns = PgRowColumns::new(&row);
+    assert_eq!(columns.len(), 2);
+
+    let (name, value) = columns.next().unwrap();
+    assert_eq!(name, "id");
+    assert_eq!(i32::deserialize(value).unwrap(), 1);
+    assert_eq!(columns.len(), 1);
+
+    // Errors still name the column
+    let (_, value) = columns.next().unwrap();
+    let err = i32::deserialize(value).unwrap_err();
+    assert!(err.to_string().contains("name"), "{err}");
+
+    assert!(columns.next().is_none());
+    assert_eq!(columns.len(), 0);
+}
+
+#[tokio::test]
+async fn debug_shows_position_not_values() {
+    let row = fetch_row("SELECT 'secret' AS name").await;
+
+    let columns = PgRowColumns::new(&row);
+    assert_eq!(
+        format!("{columns:?}"),
+        "PgRowColumns { columns: 1, index: 0, .. }"
+    );
+}
@@ -0,0 +1,88 @@
+#![cfg(feature = "time")]
+
+mod util;
+
+use serde::Deserialize;
+use serde_json::json;
+use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+use util::fetch_one;
+
+fn date() -> Date {
+    Date::from_calendar_date(2024, Month::February, 29).unwrap()
+}
+
+fn time() -> Time {
+    Time::from_hms_milli(12, 30, 0, 250).unwrap()
+}
+
+#[tokio::test]
+async fn date_as_time_date() {
+    let row: Date = fetch_one("SELECT '2024-02-29'::DATE").await.unwrap();
+    assert_eq!(row, date());
+}
+
+#[tokio::test]
+async fn time_as_time_time() {
+    let row: Time = fetch_one("SELECT '12:30:00.25'::TIME").await.unwrap();
+    assert_eq!(row, time());
+}
+
+#[tokio::test]
+async fn timestamp_as_primitive_date_time() {
+    let row: PrimitiveDateTime = fetch_one("SELECT '2024-02-29 12:30:00.25'::TIMESTAMP")
+        .await
+        .unwrap();
+    assert_eq!(row, PrimitiveDateTime::new(date(), time()));
+}
+
+#[tokio::test]
+async fn timestamptz_as_offset_date_time() {
+    let row: OffsetDateTime = fetch_one("SELECT '2024-02-29 18:00:00.25+05:30'::TIMESTAMPTZ")
+        .await
+        .unwrap();
+    assert_eq!(row, PrimitiveDateTime::new(date(), time()).assume_utc());
+    assert_eq!(row.offset(), UtcOffset::UTC);
+}
+
+#[tokio::test]
+async fn struct_with_time_fields() {
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Event {
+        day: Date,
+        at: Option<OffsetDateTime>,
+        label: String,
+    }
+
+    let row: Event =
+        fetch_one("SELECT '2024-02-29'::DATE AS day, NULL::TIMESTAMPTZ AS at, 'leap day' AS label")
+            .await
+            .unwrap();
+    assert_eq!(
+        row,
+        Event {
+            day: date(),
+            at: None,
+            label: "leap day".to_owned()
+        }
+    );
+}
+
+#[tokio::test]
+async fn strings_and_chrono_keep_their_formats() {
+    let row: (String, String, chrono::NaiveDate) = fetch_one(
+        "SELECT '12:30:00'::TIME, '2024-02-29 12:30:00+00'::TIMESTAMPTZ, '2024-02-29'::DATE",
+    )
+    .await
+    .unwrap();
+    assert_eq!(row.0, "12:30:00");
+    assert_eq!(row.1, "2024-02-29T12:30:00+00:00");
+    assert_eq!(row.2, chrono::NaiveDate::from_ymd_opt(2024, 2, 29).unwrap());
+}
+
+#[tokio::test]
+async fn json_values_use_the_time_formats() {
+    let row: serde_json::Value = fetch_one("SELECT '2024-02-29 12:30:00.25'::TIMESTAMP")
+        .await
+        .unwrap();
+    assert_eq!(row, json!("2024-02-29 12:30:00.25"));
+}
@@ -0,0 +1,49 @@
+mod util;
+
+use serde_json::{json, Value};
+use serde_sqlx::PgJson;
+use sqlx::{Decode, Postgres, Row};
+use util::fetch_row;
+
+const DOC: &str = r#"{"big": 9223372036854775807, "unsigned": 18446744073709551615, "float": 2.5, "text": "ünïcode \"quoted\"", "list": [1, null, true, {"nested": []}]}"#;
+
+fn expected() -> Value {
+    json!({
+        "big": i64::MAX,
+        "unsigned": u64::MAX,
+        "float": 2.5,
+        "text": "ünïcode \"quoted\"",
+        "list": [1, null, true, { "nested": [] }],
+    })
+}
+
+#[tokio::test]
+async fn conversions_are_lossless() {
+    let row = fetch_row(&format!(
+        "SELECT '{DOC}'::JSON AS json, '{DOC}'::JSONB AS jsonb"
+    ))
+    .await;
+
+    for column in ["json", "jsonb"] {
+        let doc: PgJson = row.try_get(column).unwrap();
+        assert_eq!(doc.as_ref(), &expected());
+        assert_eq!(doc.into_inner(), expected());
+
+        let doc: PgJson = row.try_get(column).unwrap();
+        assert_eq!(Value::from(doc), expected());
+
+        // The same value sqlx's own JSON support decodes
+        let sqlx_value: Value = row.try_get(column).unwrap();
+        assert_eq!(sqlx_value, expected());
+    }
+}
+
+#[tokio::test]
+async fn non_json_values_are_an_error() {
+    let row = fetch_row("SELECT 1 AS id").await;
+
+    assert!(row.try_get::<PgJson, _>("id").is_err());
+
+    let raw = row.try_get_raw("id").unwrap();
+    assert!(<PgJson as Decode<Postgres>>::decode(raw).is_err());
+}
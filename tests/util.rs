@@ -1,5 +1,7 @@
-use sqlx::{postgres::PgPoolOptions, PgPool};
-use tokio::sync::OnceCell;
+use sqlx::{
+    postgres::{PgPoolOptions, PgRow},
+    PgPool,
+};
 
 #[allow(unused)]
 pub async fn fetch_one<T: for<'de> serde::Deserialize<'de>>(query: &str) -> anyhow::Result<T> {
@@ -14,10 +16,9 @@ pub async fn fetch_one<T: for<'de> serde::Deserialize<'de>>(query: &str) -> anyh
 pub async fn fetch_all<T: for<'de> serde::Deserialize<'de>>(query: &str) -> anyhow::Result<Vec<T>> {
     let conn = conn().await;
 
-    let row = sqlx::query(query).fetch_all(&conn).await.unwrap();
-    let result: Result<Vec<_>, _> = row.into_iter().map(serde_sqlx::from_pg_row).collect();
+    let rows = sqlx::query(query).fetch_all(&conn).await.unwrap();
 
-    result.map_err(Into::into)
+    serde_sqlx::from_pg_rows(rows).map_err(Into::into)
 }
 
 #[allow(unused)]
@@ -33,13 +34,35 @@ pub async fn fetch_optional<T: for<'de> serde::Deserialize<'de>>(
         .map_err(Into::into)
 }
 
-async fn conn() -> PgPool {
-    static CONN: OnceCell<PgPool> = OnceCell::const_new();
+#[allow(unused)]
+pub async fn fetch_row(query: &str) -> PgRow {
+    let conn = conn().await;
+
+    sqlx::query(query).fetch_one(&conn).await.unwrap()
+}
+
+#[allow(unused)]
+pub async fn fetch_rows(query: &str) -> Vec<PgRow> {
+    let conn = conn().await;
 
-    async fn init() -> PgPool {
-        let conn_string = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set");
-        PgPoolOptions::new().connect(&conn_string).await.unwrap()
-    }
+    sqlx::query(query).fetch_all(&conn).await.unwrap()
+}
+
+#[allow(unused)]
+pub async fn execute(query: &str) {
+    let conn = conn().await;
 
-    CONN.get_or_init(init).await.clone()
+    sqlx::query(query).execute(&conn).await.unwrap();
+}
+
+/// Every `#[tokio::test]` runs on its own runtime, and a pool's connections
+/// can't outlive the runtime that opened them, so each test gets its own pool
+#[allow(unused)]
+pub async fn conn() -> PgPool {
+    let conn_string = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    PgPoolOptions::new()
+        .max_connections(1)
+        .connect(&conn_string)
+        .await
+        .unwrap()
 }